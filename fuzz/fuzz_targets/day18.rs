@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc23::y2023::{day18_1, day18_2};
+use libfuzzer_sys::fuzz_target;
+
+// malformed input must come back as an error, never as a panic
+fuzz_target!(|data: &[u8]| {
+    if let Ok(content) = std::str::from_utf8(data) {
+        let _ = day18_1::validate(content);
+        let _ = day18_2::validate(content);
+    }
+});
@@ -0,0 +1,70 @@
+use std::{convert::Infallible, fmt, str::FromStr};
+
+/// A puzzle answer. Every 2023 answer is a number, but some years ask for text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i64),
+    Text(String),
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Int(value)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Int(value.into())
+    }
+}
+
+/// Falls back to text for answers that do not fit an `i64`.
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        match i64::try_from(value) {
+            Ok(value) => Answer::Int(value),
+            Err(_) => Answer::Text(value.to_string()),
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return Ok(match s.parse() {
+            Ok(value) => Answer::Int(value),
+            Err(_) => Answer::Text(s.to_string()),
+        });
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{value}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        for s in ["142", "-3", "EZFCHJAB"] {
+            let answer: Answer = s.parse().unwrap();
+            assert_eq!(answer.to_string(), s);
+        }
+        assert_eq!("142".parse(), Ok(Answer::Int(142)));
+        assert_eq!(Answer::from(7), Answer::Int(7));
+        assert_eq!(Answer::from(7i128), Answer::Int(7));
+        assert_eq!(
+            Answer::from(1i128 << 64).to_string(),
+            "18446744073709551616"
+        );
+    }
+}
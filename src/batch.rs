@@ -0,0 +1,192 @@
+use std::{collections::HashMap, fs, io, path::Path, time::Duration};
+
+use crate::{
+    registry,
+    runner::{self, Outcome},
+    Answer, Error,
+};
+
+/// Optional file inside the batch directory with known answers, one
+/// `<input file> <part> <answer>` per line.
+pub const EXPECTED_FILE: &str = "answers.txt";
+
+#[derive(Debug)]
+pub struct Cell {
+    pub part: u32,
+    pub outcome: Outcome,
+    pub expected: Option<Answer>,
+}
+impl Cell {
+    pub fn flagged(&self) -> bool {
+        match (&self.outcome, &self.expected) {
+            (Outcome::Solved { answer, .. }, Some(expected)) => answer != expected,
+            (Outcome::Solved { .. }, None) => false,
+            _ => true,
+        }
+    }
+
+    fn render(&self) -> String {
+        match &self.outcome {
+            Outcome::Solved {
+                answer, elapsed, ..
+            } => match &self.expected {
+                Some(expected) if answer != expected => {
+                    format!("WRONG {answer} (expected {expected})")
+                }
+                _ => format!("{answer} ({}ms)", elapsed.as_millis()),
+            },
+            Outcome::Failed(Error::Panicked(msg)) => format!("PANIC {msg}"),
+            Outcome::Failed(e) => format!("ERROR {e}"),
+            Outcome::TimedOut(budget) => format!("TIMEOUT >{}s", budget.as_secs_f32()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Row {
+    pub input: String,
+    pub cells: Vec<Cell>,
+}
+
+fn parse_expected(content: &str) -> HashMap<(String, u32), Answer> {
+    let mut expected = HashMap::new();
+    for line in content.lines() {
+        let tokens = line.split_whitespace().collect::<Vec<_>>();
+        if let [name, part, answer] = tokens[..] {
+            if let (Ok(part), Ok(answer)) = (part.parse(), answer.parse()) {
+                expected.insert((name.to_string(), part), answer);
+            }
+        }
+    }
+    return expected;
+}
+
+/// Run every registered part of `day` of `year` against every input file in `dir`.
+pub fn run(year: u32, day: u32, dir: &Path, budget: Duration) -> io::Result<Vec<Row>> {
+    let solvers = registry::parts_of(year, day);
+    let expected = match fs::read_to_string(dir.join(EXPECTED_FILE)) {
+        Ok(content) => parse_expected(&content),
+        Err(_) => HashMap::new(),
+    };
+    let mut names = fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_file())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .filter(|name| name != EXPECTED_FILE)
+        .collect::<Vec<_>>();
+    names.sort();
+
+    let mut rows = vec![];
+    for name in names {
+        let content = fs::read_to_string(dir.join(&name))?;
+        let cells = solvers
+            .iter()
+            .map(|solver| Cell {
+                part: solver.part,
+                outcome: runner::run_with_budget(solver, content.clone(), budget),
+                expected: expected.get(&(name.clone(), solver.part)).cloned(),
+            })
+            .collect();
+        rows.push(Row { input: name, cells });
+    }
+    return Ok(rows);
+}
+
+pub fn render(rows: &[Row]) -> String {
+    let mut table = vec![];
+    let parts = match rows.first() {
+        Some(row) => row.cells.iter().map(|c| c.part).collect::<Vec<_>>(),
+        None => vec![],
+    };
+    let mut header = vec!["input".to_string()];
+    header.extend(parts.iter().map(|p| format!("part {p}")));
+    header.push(String::new());
+    table.push(header);
+    for row in rows {
+        let mut line = vec![row.input.clone()];
+        line.extend(row.cells.iter().map(|c| c.render()));
+        let flagged = row.cells.iter().any(|c| c.flagged());
+        line.push(if flagged {
+            "<-- !!".into()
+        } else {
+            String::new()
+        });
+        table.push(line);
+    }
+    // pad every column to the widest cell
+    let ncols = table[0].len();
+    let widths = (0..ncols)
+        .map(|i| table.iter().map(|line| line[i].len()).max().unwrap())
+        .collect::<Vec<_>>();
+    let mut out = String::new();
+    for line in table {
+        let padded = line
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &w)| format!("{cell:w$}"))
+            .collect::<Vec<_>>();
+        out += padded.join("  ").trim_end();
+        out += "\n";
+    }
+    return out;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Facts;
+
+    #[test]
+    fn test_parse_expected() {
+        let expected = parse_expected("alice.txt 1 6440\nalice.txt 2 5905\n\nbob.txt x 1\n");
+        assert_eq!(expected.len(), 2);
+        assert_eq!(expected[&("alice.txt".to_string(), 2)], Answer::Int(5905));
+    }
+
+    #[test]
+    fn test_flagged() {
+        let solved = |expected| Cell {
+            part: 1,
+            outcome: Outcome::Solved {
+                answer: Answer::Int(8),
+                facts: Facts::new(),
+                elapsed: Duration::ZERO,
+            },
+            expected,
+        };
+        assert!(!solved(None).flagged());
+        assert!(!solved(Some(Answer::Int(8))).flagged());
+        assert!(solved(Some(Answer::Int(9))).flagged());
+        let panicked = Cell {
+            part: 2,
+            outcome: Outcome::Failed(Error::Panicked("boom".into())),
+            expected: None,
+        };
+        assert!(panicked.flagged());
+    }
+
+    #[test]
+    fn test_run_dir() {
+        let dir = std::env::temp_dir().join(format!("aoc23-batch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("example.txt"),
+            "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet",
+        )
+        .unwrap();
+        fs::write(
+            dir.join(EXPECTED_FILE),
+            "example.txt 1 142\nexample.txt 2 0\n",
+        )
+        .unwrap();
+        let rows = run(2023, 1, &dir, Duration::from_secs(10)).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(rows.len(), 1);
+        assert!(!rows[0].cells[0].flagged());
+        assert!(rows[0].cells[1].flagged()); // part 2 on this example is 142 too
+        let table = render(&rows);
+        assert!(table.starts_with("input"));
+        assert!(table.contains("WRONG 142 (expected 0)"));
+    }
+}
@@ -0,0 +1,156 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    registry::Solver,
+    store::{Key, STATE_DIR},
+    Answer,
+};
+
+pub fn path() -> PathBuf {
+    return Path::new(STATE_DIR).join("cache.txt");
+}
+
+/// 64-bit FNV-1a, unlike `DefaultHasher` it stays the same across Rust versions.
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    // http://www.isthe.com/chongo/tech/comp/fnv/index.html
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for &byte in bytes {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    return hash;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Entry {
+    version: u64,
+    input: u64,
+    answer: Answer,
+}
+
+/// The last answer of every puzzle part, valid while neither the solver nor the input changes.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Cache {
+    entries: BTreeMap<Key, Entry>,
+}
+impl Cache {
+    /// Reads `<year> <day> <part> <solver version> <input hash> <answer>` lines,
+    /// a missing file is empty.
+    /// Lines that do not parse are dropped, the cache is only a shortcut.
+    pub fn load(path: &Path) -> io::Result<Cache> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Cache::default()),
+            Err(e) => return Err(e),
+        };
+        let mut entries = BTreeMap::new();
+        for line in content.lines() {
+            let tokens = line.splitn(6, ' ').collect::<Vec<_>>();
+            let [year, day, part, version, input, answer] = tokens[..] else {
+                continue;
+            };
+            if let (Ok(year), Ok(day), Ok(part), Ok(version), Ok(input)) = (
+                year.parse(),
+                day.parse(),
+                part.parse(),
+                u64::from_str_radix(version, 16),
+                u64::from_str_radix(input, 16),
+            ) {
+                let Ok(answer) = answer.parse::<Answer>();
+                let entry = Entry {
+                    version,
+                    input,
+                    answer,
+                };
+                entries.insert((year, day, part), entry);
+            }
+        }
+        return Ok(Cache { entries });
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut content = String::new();
+        for ((year, day, part), entry) in &self.entries {
+            content += &format!(
+                "{year} {day} {part} {:016x} {:016x} {}\n",
+                entry.version, entry.input, entry.answer
+            );
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        return fs::write(path, content);
+    }
+
+    /// The cached answer, unless the solver or the input changed since.
+    pub fn get(&self, solver: &Solver, input: &str) -> Option<&Answer> {
+        let entry = self.entries.get(&(solver.year, solver.day, solver.part))?;
+        let fresh = entry.version == solver.version() && entry.input == fnv1a(input.as_bytes());
+        return fresh.then_some(&entry.answer);
+    }
+
+    pub fn insert(&mut self, solver: &Solver, input: &str, answer: Answer) {
+        let entry = Entry {
+            version: solver.version(),
+            input: fnv1a(input.as_bytes()),
+            answer,
+        };
+        let key = (solver.year, solver.day, solver.part);
+        self.entries.insert(key, entry);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn test_fnv1a() {
+        assert_eq!(fnv1a(b""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a(b"a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(fnv1a(b"foobar"), 0x85944171f73967e8);
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let dir = std::env::temp_dir().join(format!("aoc23-cache-{}", std::process::id()));
+        let path = dir.join("cache.txt");
+        let (day9, day7) = (
+            registry::find(2023, 9, 1).unwrap(),
+            registry::find(2023, 7, 2).unwrap(),
+        );
+        let mut cache = Cache::load(&path).unwrap();
+        assert_eq!(cache, Cache::default());
+        cache.insert(day9, "0 3 6", Answer::Int(9));
+        cache.insert(day7, "32T3K 765", Answer::Text(String::from("two words")));
+        cache.save(&path).unwrap();
+        let loaded = Cache::load(&path).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(loaded, cache);
+        assert_eq!(loaded.get(day9, "0 3 6"), Some(&Answer::Int(9)));
+        assert_eq!(loaded.get(day9, "0 3 7"), None);
+        assert_eq!(
+            loaded.get(registry::find(2023, 9, 2).unwrap(), "0 3 6"),
+            None
+        );
+        assert_eq!(
+            loaded.get(day7, "32T3K 765"),
+            Some(&Answer::Text(String::from("two words")))
+        );
+    }
+
+    #[test]
+    fn test_solver_changed() {
+        let solver = registry::find(2023, 9, 1).unwrap();
+        let mut cache = Cache::default();
+        cache.insert(solver, "0 3 6", Answer::Int(9));
+        cache.entries.get_mut(&(2023, 9, 1)).unwrap().version ^= 1;
+        assert_eq!(cache.get(solver, "0 3 6"), None);
+    }
+}
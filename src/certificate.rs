@@ -0,0 +1,133 @@
+//! Answer certificates: evidence for an answer that a checker validates against the input,
+//! so the answer does not rest on trusting the solver that found it.
+use std::fmt;
+
+use crate::{
+    geometry::{Dir, Pos},
+    Answer, Error,
+};
+
+/// Which line a pattern mirrors along.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mirror {
+    /// Below this many rows.
+    Rows(usize),
+    /// Right of this many columns.
+    Columns(usize),
+}
+
+/// Evidence for an answer, checked by the `check` of the solver module.
+/// For the puzzles that ask for a minimum or maximum it shows the answer is reachable,
+/// not that nothing better exists.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Certificate {
+    /// Positions a route visits in order, both ends included.
+    Path(Vec<Pos>),
+    /// The input value that produces the answer, e.g. the seed with the lowest location.
+    Witness(i64),
+    /// The mirror line of every pattern, in input order.
+    Mirrors(Vec<Mirror>),
+}
+
+impl fmt::Display for Certificate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Certificate::Path(path) => {
+                write!(f, "path of {} steps:", path.len().saturating_sub(1))?;
+                for pos in path {
+                    write!(f, " {},{}", pos.y, pos.x)?;
+                }
+                Ok(())
+            }
+            Certificate::Witness(value) => write!(f, "witness {value}"),
+            Certificate::Mirrors(mirrors) => {
+                let mirrors = mirrors
+                    .iter()
+                    .map(|mirror| match mirror {
+                        Mirror::Rows(n) => format!("below row {n}"),
+                        Mirror::Columns(n) => format!("right of column {n}"),
+                    })
+                    .collect::<Vec<_>>();
+                write!(f, "mirrors {}", mirrors.join(", "))
+            }
+        }
+    }
+}
+
+/// Rejects a certificate that claims a different answer.
+pub fn claims(answer: &Answer, value: impl Into<Answer>) -> Result<(), Error> {
+    let value = value.into();
+    if *answer != value {
+        return Err(Error::rejected(format!(
+            "it adds up to {value}, not {answer}"
+        )));
+    }
+    return Ok(());
+}
+
+/// The direction of every step of `path`, rejecting jumps between positions that do not touch.
+pub fn directions(path: &[Pos]) -> Result<Vec<Dir>, Error> {
+    return path
+        .windows(2)
+        .map(|step| {
+            let (from, to) = (step[0], step[1]);
+            return Dir::ALL
+                .into_iter()
+                .find(|&dir| from.step(dir) == to)
+                .ok_or_else(|| {
+                    let msg = format!("jumps from {},{} to {},{}", from.y, from.x, to.y, to.x);
+                    return Error::rejected(msg);
+                });
+        })
+        .collect();
+}
+
+/// Directions of a path as straight runs, e.g. right 3 times, then down twice.
+pub fn runs(dirs: &[Dir]) -> Vec<(Dir, usize)> {
+    let mut runs: Vec<(Dir, usize)> = vec![];
+    for &dir in dirs {
+        match runs.last_mut() {
+            Some((last, len)) if *last == dir => *len += 1,
+            _ => runs.push((dir, 1)),
+        }
+    }
+    return runs;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_directions() {
+        let path = [
+            Pos::new(0, 0),
+            Pos::new(0, 1),
+            Pos::new(0, 2),
+            Pos::new(1, 2),
+        ];
+        let dirs = directions(&path).unwrap();
+        assert_eq!(dirs, [Dir::Right, Dir::Right, Dir::Down]);
+        assert_eq!(runs(&dirs), [(Dir::Right, 2), (Dir::Down, 1)]);
+        assert_eq!(directions(&path[..1]), Ok(vec![]));
+
+        let jump = [Pos::new(0, 0), Pos::new(1, 1)];
+        assert_eq!(
+            directions(&jump),
+            Err(Error::Rejected(String::from("jumps from 0,0 to 1,1")))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let path = Certificate::Path(vec![Pos::new(0, 0), Pos::new(0, 1)]);
+        assert_eq!(path.to_string(), "path of 1 steps: 0,0 0,1");
+        let mirrors = Certificate::Mirrors(vec![Mirror::Columns(5), Mirror::Rows(4)]);
+        assert_eq!(
+            mirrors.to_string(),
+            "mirrors right of column 5, below row 4"
+        );
+        assert_eq!(claims(&Answer::Int(7), 7), Ok(()));
+        assert!(claims(&Answer::Int(7), 8).is_err());
+    }
+}
@@ -0,0 +1,85 @@
+//! Debug dumps of the intermediate structures of the solvers, off unless `-v` or `-vv` asks.
+//! Solvers call [`dump!`](crate::dump!) instead of leaving `println!`s commented out.
+use std::{
+    fmt,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Quiet as u8);
+
+/// How much the solvers dump, for every thread at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Quiet = 0,
+    /// `-v`, the parsed input and other structures dumped once per solve.
+    Debug = 1,
+    /// `-vv`, also every step of the loops.
+    Trace = 2,
+}
+impl Level {
+    /// The level of `-v` repeated `count` times, anything beyond `-vv` is still [`Level::Trace`].
+    pub fn from_count(count: usize) -> Level {
+        return match count {
+            0 => Level::Quiet,
+            1 => Level::Debug,
+            _ => Level::Trace,
+        };
+    }
+}
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn level() -> Level {
+    return Level::from_count(LEVEL.load(Ordering::Relaxed).into());
+}
+
+/// Whether dumps at `level` are on, for dumps that need a loop around [`dump!`](crate::dump!).
+pub fn enabled(level: Level) -> bool {
+    return level <= self::level();
+}
+
+/// One line on stderr, prefixed with the last part of `module`, e.g. `day15_2`.
+/// Rendered grids end with a newline already, it is dropped.
+pub fn write(module: &str, args: fmt::Arguments) {
+    let name = module.rsplit("::").next().unwrap_or(module);
+    eprintln!("{name}: {}", args.to_string().trim_end());
+}
+
+/// `dump!(Debug, "{boxes:?}")` writes a line on stderr when that level is on.
+/// The arguments are not evaluated otherwise, so it is cheap inside hot loops.
+#[macro_export]
+macro_rules! dump {
+    ($level:ident, $($arg:tt)*) => {
+        if $crate::dump::enabled($crate::dump::Level::$level) {
+            $crate::dump::write(module_path!(), format_args!($($arg)*));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_levels() {
+        assert_eq!(Level::from_count(0), Level::Quiet);
+        assert_eq!(Level::from_count(2), Level::Trace);
+        assert_eq!(Level::from_count(5), Level::Trace);
+
+        let evaluated = Cell::new(0);
+        let count = || evaluated.replace(evaluated.get() + 1);
+        assert_eq!(level(), Level::Quiet);
+        crate::dump!(Debug, "{}", count());
+        assert_eq!(evaluated.get(), 0);
+
+        set_level(Level::Debug);
+        assert!(enabled(Level::Debug) && !enabled(Level::Trace));
+        crate::dump!(Debug, "{}", count());
+        crate::dump!(Trace, "{}", count());
+        set_level(Level::Quiet);
+        assert_eq!(evaluated.get(), 1);
+    }
+}
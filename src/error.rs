@@ -0,0 +1,51 @@
+use std::{fmt, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// There is no solver registered for this day and part.
+    NoSolver { year: u32, day: u32, part: u32 },
+    /// The input does not look like a puzzle input for this day.
+    Parse(String),
+    /// The solver panicked, usually because of an input it did not expect.
+    Panicked(String),
+    /// A parameter override or the config file is invalid.
+    Param(String),
+    /// An answer certificate does not hold up against the input.
+    Rejected(String),
+    /// The solver stopped because its [`crate::Progress`] was cancelled, e.g. out of time.
+    Cancelled,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NoSolver { year, day, part } => {
+                write!(f, "{year} day {day} part {part} is not solved")
+            }
+            Error::Parse(msg) => write!(f, "parse error: {msg}"),
+            Error::Panicked(msg) => write!(f, "solver panicked: {msg}"),
+            Error::Param(msg) => write!(f, "bad parameter: {msg}"),
+            Error::Rejected(msg) => write!(f, "certificate rejected: {msg}"),
+            Error::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl Error {
+    pub(crate) fn parse(msg: impl Into<String>) -> Self {
+        Error::Parse(msg.into())
+    }
+
+    pub(crate) fn rejected(msg: impl Into<String>) -> Self {
+        Error::Rejected(msg.into())
+    }
+}
+
+/// Parse a number, reporting the offending text on failure.
+pub(crate) fn parse_num<T: FromStr>(s: &str) -> Result<T, Error> {
+    return s
+        .parse()
+        .map_err(|_| Error::parse(format!("bad number: {s:?}")));
+}
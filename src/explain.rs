@@ -0,0 +1,69 @@
+use std::fmt::Display;
+
+/// One intermediate result of a solver, e.g. the detected cycle length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fact {
+    pub name: &'static str,
+    pub value: String,
+}
+
+/// Intermediate results a solver records so its answer can be audited.
+/// Solvers always get one, it is only shown in explain mode.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Facts {
+    facts: Vec<Fact>,
+}
+impl Facts {
+    pub fn new() -> Self {
+        return Facts::default();
+    }
+
+    pub fn note(&mut self, name: &'static str, value: impl Display) {
+        self.facts.push(Fact {
+            name,
+            value: value.to_string(),
+        });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.facts.is_empty();
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Fact> {
+        return self.facts.iter();
+    }
+
+    /// Value of the first fact called `name`.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        return self
+            .facts
+            .iter()
+            .find(|fact| fact.name == name)
+            .map(|fact| fact.value.as_str());
+    }
+
+    /// One `name: value` line per fact, indented by two spaces.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        for fact in &self.facts {
+            out += &format!("  {}: {}\n", fact.name, fact.value);
+        }
+        return out;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_facts() {
+        let mut facts = Facts::new();
+        assert!(facts.is_empty());
+        facts.note("cycle length", 7);
+        facts.note("winning seed", "82");
+        assert_eq!(facts.get("cycle length"), Some("7"));
+        assert_eq!(facts.get("offset"), None);
+        assert_eq!(facts.render(), "  cycle length: 7\n  winning seed: 82\n");
+    }
+}
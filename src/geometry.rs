@@ -0,0 +1,191 @@
+use std::ops::{Add, Mul, Sub};
+
+/// One of the four grid directions, `Up` goes towards row 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+impl Dir {
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Down, Dir::Left, Dir::Right];
+
+    /// Unit step as `(dy, dx)`.
+    pub fn dydx(self) -> (i64, i64) {
+        match self {
+            Dir::Up => (-1, 0),
+            Dir::Down => (1, 0),
+            Dir::Left => (0, -1),
+            Dir::Right => (0, 1),
+        }
+    }
+
+    /// Direction after turning 90 degrees counter-clockwise.
+    pub fn turn_left(self) -> Dir {
+        match self {
+            Dir::Up => Dir::Left,
+            Dir::Left => Dir::Down,
+            Dir::Down => Dir::Right,
+            Dir::Right => Dir::Up,
+        }
+    }
+
+    /// Direction after turning 90 degrees clockwise.
+    pub fn turn_right(self) -> Dir {
+        return self.turn_left().opposite();
+    }
+
+    pub fn opposite(self) -> Dir {
+        match self {
+            Dir::Up => Dir::Down,
+            Dir::Down => Dir::Up,
+            Dir::Left => Dir::Right,
+            Dir::Right => Dir::Left,
+        }
+    }
+}
+
+/// A point on the plane, signed so that stepping off a grid is still a position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Pos {
+    pub y: i64,
+    pub x: i64,
+}
+impl Pos {
+    pub const fn new(y: i64, x: i64) -> Pos {
+        return Pos { y, x };
+    }
+
+    pub fn step(self, dir: Dir) -> Pos {
+        return self.step_by(dir, 1);
+    }
+
+    pub fn step_by(self, dir: Dir, n: i64) -> Pos {
+        let (dy, dx) = dir.dydx();
+        return Pos::new(self.y + dy * n, self.x + dx * n);
+    }
+
+    pub fn manhattan(self, other: Pos) -> i64 {
+        return (self.y - other.y).abs() + (self.x - other.x).abs();
+    }
+}
+
+impl Add for Pos {
+    type Output = Pos;
+    fn add(self, other: Pos) -> Pos {
+        return Pos::new(self.y + other.y, self.x + other.x);
+    }
+}
+
+impl Sub for Pos {
+    type Output = Pos;
+    fn sub(self, other: Pos) -> Pos {
+        return Pos::new(self.y - other.y, self.x - other.x);
+    }
+}
+
+impl Mul<i64> for Pos {
+    type Output = Pos;
+    fn mul(self, n: i64) -> Pos {
+        return Pos::new(self.y * n, self.x * n);
+    }
+}
+
+impl From<Dir> for Pos {
+    fn from(dir: Dir) -> Pos {
+        let (dy, dx) = dir.dydx();
+        return Pos::new(dy, dx);
+    }
+}
+
+/// Size of a `h` by `w` grid, all bounds checks go through here.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    pub h: usize,
+    pub w: usize,
+}
+impl Bounds {
+    pub fn new(h: usize, w: usize) -> Bounds {
+        return Bounds { h, w };
+    }
+
+    pub fn contains(self, pos: Pos) -> bool {
+        return 0 <= pos.y && pos.y < self.h as i64 && 0 <= pos.x && pos.x < self.w as i64;
+    }
+
+    /// `(row, column)` of `pos`, if it is on the grid.
+    pub fn index(self, pos: Pos) -> Option<(usize, usize)> {
+        return self
+            .contains(pos)
+            .then_some((pos.y as usize, pos.x as usize));
+    }
+
+    /// The cell next to `pos` in `dir`, if it is on the grid.
+    pub fn neighbour(self, pos: Pos, dir: Dir) -> Option<Pos> {
+        let next = pos.step(dir);
+        return self.contains(next).then_some(next);
+    }
+
+    /// The cells next to `pos` that are on the grid, with the direction taken to reach them.
+    pub fn neighbours(self, pos: Pos) -> impl Iterator<Item = (Dir, Pos)> {
+        return Dir::ALL
+            .into_iter()
+            .filter_map(move |dir| self.neighbour(pos, dir).map(|next| (dir, next)));
+    }
+
+    /// Every position on the grid, row by row.
+    pub fn positions(self) -> impl Iterator<Item = Pos> {
+        return (0..self.h as i64)
+            .flat_map(move |y| (0..self.w as i64).map(move |x| Pos::new(y, x)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        for dir in Dir::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_left().turn_left(), dir.opposite());
+            assert_eq!(dir.opposite().opposite(), dir);
+            // a left turn is a counter-clockwise rotation of (dy, dx)
+            let (dy, dx) = dir.dydx();
+            assert_eq!(dir.turn_left().dydx(), (-dx, dy));
+        }
+        assert_eq!(Dir::Up.turn_right(), Dir::Right);
+    }
+
+    #[test]
+    fn test_pos() {
+        let pos = Pos::new(2, 3);
+        assert_eq!(pos.step(Dir::Up), Pos::new(1, 3));
+        assert_eq!(pos.step_by(Dir::Left, 5), Pos::new(2, -2));
+        assert_eq!(pos + Pos::from(Dir::Down) * 2, Pos::new(4, 3));
+        assert_eq!(pos - Pos::new(2, 3), Pos::default());
+        assert_eq!(pos.manhattan(Pos::new(-1, 4)), 4);
+    }
+
+    #[test]
+    fn test_bounds() {
+        let bounds = Bounds::new(2, 3);
+        assert!(bounds.contains(Pos::new(1, 2)));
+        assert!(!bounds.contains(Pos::new(2, 0)));
+        assert!(!bounds.contains(Pos::new(0, -1)));
+        assert_eq!(bounds.index(Pos::new(1, 0)), Some((1, 0)));
+        assert_eq!(bounds.index(Pos::new(-1, 0)), None);
+        // the corner has two neighbours, column 1 still has its left one
+        let corner = bounds.neighbours(Pos::new(0, 0)).collect::<Vec<_>>();
+        assert_eq!(
+            corner,
+            [(Dir::Down, Pos::new(1, 0)), (Dir::Right, Pos::new(0, 1))]
+        );
+        assert_eq!(
+            bounds.neighbour(Pos::new(0, 1), Dir::Left),
+            Some(Pos::new(0, 0))
+        );
+        assert_eq!(bounds.positions().count(), 6);
+    }
+}
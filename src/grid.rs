@@ -0,0 +1,293 @@
+use std::ops::{Index, IndexMut};
+
+use crate::{
+    geometry::{Bounds, Pos},
+    Error,
+};
+
+/// A rectangular grid stored row-major in one `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    h: usize,
+    w: usize,
+}
+impl<T> Grid<T> {
+    /// An `h` by `w` grid with every cell set to `fill`.
+    pub fn new(h: usize, w: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        return Grid {
+            cells: vec![fill; h * w],
+            h,
+            w,
+        };
+    }
+
+    /// Grid from its rows, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>, Error> {
+        let (h, w) = (rows.len(), rows.first().map_or(0, |row| row.len()));
+        if let Some(y) = rows.iter().position(|row| row.len() != w) {
+            return Err(Error::parse(format!(
+                "row {y} has {} cells, expected {w}",
+                rows[y].len()
+            )));
+        }
+        let cells = rows.into_iter().flatten().collect();
+        return Ok(Grid { cells, h, w });
+    }
+
+    /// Parse a char map, one line per row. `cell` returns `None` for chars it does not accept.
+    /// Puzzle maps are ASCII, anything else is rejected.
+    pub fn parse(content: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Grid<T>, Error> {
+        return Grid::parse_bytes(content.as_bytes(), |b| match b.is_ascii() {
+            true => cell(b as char),
+            false => None,
+        });
+    }
+
+    /// Parse a byte map, one line per row, straight into the cells without a vector per row.
+    /// `cell` returns `None` for bytes it does not accept. Line ends may be `\n` or `\r\n`.
+    pub fn parse_bytes(
+        content: &[u8],
+        mut cell: impl FnMut(u8) -> Option<T>,
+    ) -> Result<Grid<T>, Error> {
+        let content = content.strip_suffix(b"\n").unwrap_or(content);
+        if content.is_empty() {
+            return Err(Error::parse("empty grid"));
+        }
+        let mut cells = Vec::with_capacity(content.len());
+        let (mut h, mut w) = (0, 0);
+        for (y, line) in content.split(|&b| b == b'\n').enumerate() {
+            let line = line.strip_suffix(b"\r").unwrap_or(line);
+            if y == 0 {
+                w = line.len();
+            } else if line.len() != w {
+                return Err(Error::parse(format!(
+                    "row {y} has {} cells, expected {w}",
+                    line.len()
+                )));
+            }
+            for (x, &b) in line.iter().enumerate() {
+                let value = cell(b).ok_or_else(|| match b.is_ascii() {
+                    true => Error::parse(format!("unexpected {:?} at {y}:{x}", b as char)),
+                    false => Error::parse(format!("unexpected byte {b:#04x} at {y}:{x}")),
+                })?;
+                cells.push(value);
+            }
+            h += 1;
+        }
+        return Ok(Grid { cells, h, w });
+    }
+
+    pub fn h(&self) -> usize {
+        return self.h;
+    }
+
+    pub fn w(&self) -> usize {
+        return self.w;
+    }
+
+    pub fn bounds(&self) -> Bounds {
+        return Bounds::new(self.h, self.w);
+    }
+
+    /// The cell at `pos`, or `None` off the grid.
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        let (y, x) = self.bounds().index(pos)?;
+        return Some(&self.cells[y * self.w + x]);
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        let (y, x) = self.bounds().index(pos)?;
+        return Some(&mut self.cells[y * self.w + x]);
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        return &self.cells[y * self.w..(y + 1) * self.w];
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks_exact panics on 0, a grid without columns has no cells anyway
+        return self.cells.chunks_exact(self.w.max(1));
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        return self
+            .cells
+            .iter()
+            .skip(x)
+            .step_by(self.w.max(1))
+            .take(self.h);
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        return (0..self.w).map(|x| self.column(x));
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        return self.bounds().positions().zip(self.cells.iter());
+    }
+
+    /// Position of the first cell equal to `value`, row by row.
+    pub fn find(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        return self
+            .iter()
+            .find(|(_, cell)| *cell == value)
+            .map(|(pos, _)| pos);
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        return Grid {
+            cells: self.cells.iter().map(f).collect(),
+            h: self.h,
+            w: self.w,
+        };
+    }
+
+    /// Rows become columns.
+    pub fn transposed(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        return Grid {
+            cells: self.columns().flatten().cloned().collect(),
+            h: self.w,
+            w: self.h,
+        };
+    }
+
+    /// The grid turned a quarter clockwise, the first column becomes the first row reversed.
+    pub fn rotated_clockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for x in 0..self.w {
+            for y in (0..self.h).rev() {
+                cells.push(self.cells[y * self.w + x].clone());
+            }
+        }
+        return Grid {
+            cells,
+            h: self.w,
+            w: self.h,
+        };
+    }
+
+    /// One line per row, each cell drawn with `draw`.
+    pub fn render(&self, mut draw: impl FnMut(&T) -> char) -> String {
+        let mut out = String::new();
+        for row in self.rows() {
+            out.extend(row.iter().map(&mut draw));
+            out.push('\n');
+        }
+        return out;
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+    fn index(&self, pos: Pos) -> &T {
+        return self.get(pos).expect("position off the grid");
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        return self.get_mut(pos).expect("position off the grid");
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+    fn index(&self, (y, x): (usize, usize)) -> &T {
+        assert!(x < self.w, "column {x} off the grid");
+        return &self.cells[y * self.w + x];
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (y, x): (usize, usize)) -> &mut T {
+        assert!(x < self.w, "column {x} off the grid");
+        return &mut self.cells[y * self.w + x];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        return Grid::parse("abc\ndef", Some).unwrap();
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("#.\n.#", |ch| match ch {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!((grid.h(), grid.w()), (2, 2));
+        assert!(grid[Pos::new(1, 1)]);
+        assert!(!grid[(1, 0)]);
+        assert_eq!(grid.get(Pos::new(2, 0)), None);
+        assert!(Grid::parse("#x", |ch| (ch == '#').then_some(())).is_err());
+        assert!(Grid::parse("ab\nc", Some).is_err());
+        assert!(Grid::parse("", Some).is_err());
+    }
+
+    #[test]
+    fn test_parse_bytes() {
+        let grid = Grid::parse_bytes(b"#.\r\n.#\r\n", |b| Some(b == b'#')).unwrap();
+        assert_eq!((grid.h(), grid.w()), (2, 2));
+        assert!(grid[(0, 0)] && grid[(1, 1)] && !grid[(0, 1)]);
+        assert_eq!(
+            Grid::parse_bytes(b"ab\nc", Some),
+            Err(Error::parse("row 1 has 1 cells, expected 2"))
+        );
+        assert_eq!(
+            Grid::parse("\u{e9}", Some),
+            Err(Error::parse("unexpected byte 0xc3 at 0:0"))
+        );
+        assert_eq!(
+            Grid::parse("#x", |ch| (ch == '#').then_some(())),
+            Err(Error::parse("unexpected 'x' at 0:1"))
+        );
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = sample();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.find(&'e'), Some(Pos::new(1, 1)));
+        assert_eq!(grid.find(&'z'), None);
+        assert_eq!(grid.render(|&ch| ch), "abc\ndef\n");
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = sample();
+        assert_eq!(grid.transposed().render(|&ch| ch), "ad\nbe\ncf\n");
+        assert_eq!(grid.transposed().transposed(), grid);
+        assert_eq!(grid.rotated_clockwise().render(|&ch| ch), "da\neb\nfc\n");
+        let mut turned = grid.clone();
+        for _ in 0..4 {
+            turned = turned.rotated_clockwise();
+        }
+        assert_eq!(turned, grid);
+        let mut upper = grid.map(|ch| ch.to_ascii_uppercase());
+        upper[Pos::new(0, 0)] = 'z';
+        assert_eq!(upper.render(|&ch| ch), "zBC\nDEF\n");
+    }
+}
@@ -0,0 +1,269 @@
+/// The integers `lo..=hi`, never empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub lo: i64,
+    pub hi: i64,
+}
+impl Interval {
+    pub fn new(lo: i64, hi: i64) -> Interval {
+        assert!(lo <= hi, "empty interval {lo}..={hi}");
+        return Interval { lo, hi };
+    }
+
+    /// `len` integers from `start`, `None` when that is empty or does not fit an `i64`.
+    pub fn with_len(start: i64, len: i64) -> Option<Interval> {
+        let hi = len.checked_sub(1).and_then(|len| start.checked_add(len))?;
+        return (len > 0).then(|| Interval::new(start, hi));
+    }
+
+    /// Number of integers, which can be one more than `i64` holds.
+    pub fn size(self) -> i128 {
+        return self.hi as i128 - self.lo as i128 + 1;
+    }
+
+    pub fn contains(self, x: i64) -> bool {
+        return self.lo <= x && x <= self.hi;
+    }
+
+    pub fn intersection(self, other: Interval) -> Option<Interval> {
+        let (lo, hi) = (self.lo.max(other.lo), self.hi.min(other.hi));
+        return (lo <= hi).then(|| Interval::new(lo, hi));
+    }
+
+    /// The parts below and above `other`.
+    pub fn difference(self, other: Interval) -> [Option<Interval>; 2] {
+        let Some(common) = self.intersection(other) else {
+            return [Some(self), None];
+        };
+        let below = (self.lo < common.lo).then(|| Interval::new(self.lo, common.lo - 1));
+        let above = (common.hi < self.hi).then(|| Interval::new(common.hi + 1, self.hi));
+        return [below, above];
+    }
+
+    /// The parts below `at` and from `at` upwards.
+    pub fn split_at(self, at: i64) -> (Option<Interval>, Option<Interval>) {
+        if at <= self.lo {
+            return (None, Some(self));
+        }
+        if at > self.hi {
+            return (Some(self), None);
+        }
+        return (
+            Some(Interval::new(self.lo, at - 1)),
+            Some(Interval::new(at, self.hi)),
+        );
+    }
+
+    pub fn shift(self, by: i64) -> Interval {
+        return Interval::new(self.lo + by, self.hi + by);
+    }
+}
+
+/// A set of integers kept as sorted, disjoint and non-touching intervals.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+impl IntervalSet {
+    pub fn new() -> IntervalSet {
+        return IntervalSet::default();
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        self.intervals.push(interval);
+        self.normalize();
+    }
+
+    fn normalize(&mut self) {
+        self.intervals.sort();
+        let mut merged: Vec<Interval> = vec![];
+        for interval in self.intervals.drain(..) {
+            match merged.last_mut() {
+                // touching intervals merge too, 1..=2 and 3..=4 is 1..=4
+                Some(last) if interval.lo <= last.hi.saturating_add(1) => {
+                    last.hi = last.hi.max(interval.hi)
+                }
+                _ => merged.push(interval),
+            }
+        }
+        self.intervals = merged;
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Interval> + '_ {
+        return self.intervals.iter().copied();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.intervals.is_empty();
+    }
+
+    /// Number of integers in the set.
+    pub fn len(&self) -> i128 {
+        return self.iter().map(Interval::size).sum();
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        return self.intervals.first().map(|interval| interval.lo);
+    }
+
+    pub fn contains(&self, x: i64) -> bool {
+        return self.iter().any(|interval| interval.contains(x));
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        return self.iter().chain(other.iter()).collect();
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        return self
+            .iter()
+            .flat_map(|a| other.iter().filter_map(move |b| a.intersection(b)))
+            .collect();
+    }
+
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut left = self.intervals.clone();
+        for b in other.iter() {
+            left = left
+                .into_iter()
+                .flat_map(|a| a.difference(b).into_iter().flatten())
+                .collect();
+        }
+        return left.into_iter().collect();
+    }
+
+    /// The parts below `at` and from `at` upwards.
+    pub fn split_at(&self, at: i64) -> (IntervalSet, IntervalSet) {
+        let (mut below, mut above) = (vec![], vec![]);
+        for interval in self.iter() {
+            let (lo, hi) = interval.split_at(at);
+            below.extend(lo);
+            above.extend(hi);
+        }
+        return (below.into_iter().collect(), above.into_iter().collect());
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> IntervalSet {
+        let mut set = IntervalSet {
+            intervals: iter.into_iter().collect(),
+        };
+        set.normalize();
+        return set;
+    }
+}
+
+/// An axis-aligned box of integer points, one interval per axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NBox<const N: usize> {
+    pub axes: [Interval; N],
+}
+impl<const N: usize> NBox<N> {
+    pub fn new(axes: [Interval; N]) -> NBox<N> {
+        return NBox { axes };
+    }
+
+    /// Number of points inside.
+    pub fn volume(&self) -> i128 {
+        return self.axes.iter().map(|axis| axis.size()).product();
+    }
+
+    pub fn contains(&self, point: [i64; N]) -> bool {
+        return self
+            .axes
+            .iter()
+            .zip(point)
+            .all(|(axis, x)| axis.contains(x));
+    }
+
+    pub fn intersection(&self, other: &NBox<N>) -> Option<NBox<N>> {
+        let mut axes = self.axes;
+        for (axis, other) in axes.iter_mut().zip(other.axes) {
+            *axis = axis.intersection(other)?;
+        }
+        return Some(NBox::new(axes));
+    }
+
+    /// The parts below `at` and from `at` upwards along `axis`.
+    pub fn split(&self, axis: usize, at: i64) -> (Option<NBox<N>>, Option<NBox<N>>) {
+        let (below, above) = self.axes[axis].split_at(at);
+        let with = |interval: Interval| {
+            let mut axes = self.axes;
+            axes[axis] = interval;
+            return NBox::new(axes);
+        };
+        return (below.map(with), above.map(with));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+        return intervals
+            .iter()
+            .map(|&(lo, hi)| Interval::new(lo, hi))
+            .collect();
+    }
+
+    #[test]
+    fn test_interval() {
+        let a = Interval::new(1, 10);
+        assert_eq!(a.size(), 10);
+        assert_eq!(Interval::with_len(5, 3), Some(Interval::new(5, 7)));
+        assert_eq!(Interval::with_len(5, 0), None);
+        assert_eq!(Interval::with_len(i64::MAX, 2), None);
+        assert_eq!(Interval::new(i64::MIN, i64::MAX).size(), 1 << 64);
+        assert_eq!(
+            a.intersection(Interval::new(8, 20)),
+            Some(Interval::new(8, 10))
+        );
+        assert_eq!(a.intersection(Interval::new(11, 20)), None);
+        assert_eq!(
+            a.difference(Interval::new(3, 4)),
+            [Some(Interval::new(1, 2)), Some(Interval::new(5, 10))]
+        );
+        assert_eq!(a.difference(Interval::new(0, 20)), [None, None]);
+        assert_eq!(
+            a.split_at(4),
+            (Some(Interval::new(1, 3)), Some(Interval::new(4, 10)))
+        );
+        assert_eq!(a.split_at(1), (None, Some(a)));
+        assert_eq!(a.split_at(11), (Some(a), None));
+        assert_eq!(a.shift(-1), Interval::new(0, 9));
+    }
+
+    #[test]
+    fn test_set() {
+        let a = set(&[(5, 8), (1, 2), (3, 4), (20, 30)]);
+        assert_eq!(a, set(&[(1, 8), (20, 30)]));
+        assert_eq!(a.len(), 19);
+        assert_eq!(a.min(), Some(1));
+        assert!(a.contains(25) && !a.contains(10));
+        let b = set(&[(7, 22)]);
+        assert_eq!(a.union(&b), set(&[(1, 30)]));
+        assert_eq!(a.intersection(&b), set(&[(7, 8), (20, 22)]));
+        assert_eq!(a.difference(&b), set(&[(1, 6), (23, 30)]));
+        assert_eq!(a.split_at(21), (set(&[(1, 8), (20, 20)]), set(&[(21, 30)])));
+        let mut c = IntervalSet::new();
+        assert!(c.is_empty());
+        c.insert(Interval::new(i64::MAX, i64::MAX));
+        c.insert(Interval::new(0, i64::MAX - 1));
+        assert_eq!(c, set(&[(0, i64::MAX)]));
+    }
+
+    #[test]
+    fn test_nbox() {
+        let cube = NBox::new([Interval::new(1, 4); 3]);
+        assert_eq!(cube.volume(), 64);
+        assert!(cube.contains([1, 2, 4]) && !cube.contains([0, 2, 4]));
+        let (below, above) = cube.split(1, 2);
+        assert_eq!(below.unwrap().volume(), 16);
+        assert_eq!(above.unwrap().volume(), 48);
+        assert_eq!(cube.split(0, 9), (Some(cube), None));
+        let shifted = NBox::new([Interval::new(3, 6); 3]);
+        assert_eq!(cube.intersection(&shifted).unwrap().volume(), 8);
+    }
+}
@@ -0,0 +1,64 @@
+//! Advent of Code solutions, 2023 so far. Every event lives in its own `yYYYY` module.
+//!
+//! ```
+//! let answer = aoc23::solve(2023, 1, 1, "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet");
+//! assert_eq!(answer, Ok(aoc23::Answer::Int(142)));
+//! ```
+mod answer;
+pub mod batch;
+pub mod cache;
+pub mod certificate;
+pub mod dump;
+mod error;
+pub mod explain;
+pub mod geometry;
+pub mod grid;
+pub mod interval;
+pub mod number_theory;
+pub mod params;
+pub mod parser;
+pub mod polygon;
+mod progress;
+pub mod registry;
+pub mod runner;
+pub mod scaffold;
+pub mod server;
+pub mod status;
+pub mod store;
+pub mod y2023;
+
+pub use answer::Answer;
+pub(crate) use error::parse_num;
+pub use error::Error;
+pub use explain::Facts;
+pub use grid::Grid;
+pub use params::Params;
+pub use progress::Progress;
+
+fn find(year: u32, day: u32, part: u32) -> Result<&'static registry::Solver, Error> {
+    return registry::find(year, day, part).ok_or(Error::NoSolver { year, day, part });
+}
+
+/// Solve `part` of `day` of the `year` event for `input`, the puzzle input text as downloaded.
+pub fn solve(year: u32, day: u32, part: u32, input: &str) -> Result<Answer, Error> {
+    let solver = find(year, day, part)?;
+    return solver.solve(input);
+}
+
+/// Like [`solve`], with some of the solver's puzzle constants changed.
+pub fn solve_with(
+    year: u32,
+    day: u32,
+    part: u32,
+    input: &str,
+    params: &Params,
+) -> Result<Answer, Error> {
+    let solver = find(year, day, part)?;
+    return solver.solve_with(input, params);
+}
+
+/// Like [`solve`], also returning the intermediate results the solver recorded.
+pub fn explain(year: u32, day: u32, part: u32, input: &str) -> Result<(Answer, Facts), Error> {
+    let solver = find(year, day, part)?;
+    return solver.explain(input, &Params::new());
+}
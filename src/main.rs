@@ -1 +1,506 @@
-fn main() {}
+use std::{
+    fs,
+    io::{self, IsTerminal},
+    net::TcpListener,
+    panic,
+    path::Path,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use aoc23::{
+    batch,
+    cache::{self, Cache},
+    dump::{self, Level},
+    params::{self, Config},
+    registry::{self, Solver},
+    runner::{self, Outcome},
+    scaffold, server, status, store, Answer, Params,
+};
+
+const USAGE: &str = "usage:
+    aoc23 run <year> <day> <part> [--param <name>=<value>]... [--explain] [--fresh] [--timeout <secs>]
+              [--algo <name>]
+                                                solve inputs/<year>/dayNN.txt, params override aoc23.toml,
+                                                --explain prints the solver's intermediate results,
+                                                --fresh recomputes a cached answer,
+                                                --timeout gives up after that long (default 300),
+                                                --algo picks another algorithm of the day
+    aoc23 run --all [--fresh] [--timeout <secs>]
+                                                solve every day of every year that has an input
+    aoc23 certify <year> <day> <part>           solve with a certificate for the answer and check it
+                                                against the input
+    aoc23 bench <year> <day> <part> [--timeout <secs>]
+                                                solve with every algorithm of the day and compare
+                                                their answers and runtimes, and time the parser
+                                                alone if the day registers it
+    aoc23 inspect <year> <day>                  print facts about the structure of the input, e.g.
+                                                its grid size, without solving it
+    aoc23 batch <year> <day> <dir> [--timeout <secs>]
+                                                solve every input file in dir
+    aoc23 status                                list solved parts, tests, answers and runtimes
+    aoc23 serve [--port <port>] [--timeout <secs>]
+                                                answer POST /solve/<year>/<day>/<part> on localhost,
+                                                the body is the input, the reply is JSON
+    aoc23 new <year> <day>                      create the modules and example fixture of a new day
+                                                and register them
+-v anywhere dumps the parsed input and other intermediate structures of the solvers on stderr,
+-vv also every step of their loops";
+
+fn parse_num(arg: Option<&String>, what: &str) -> Result<u32, String> {
+    let arg = arg.ok_or(format!("missing {what}"))?;
+    return arg.parse().map_err(|_| format!("bad {what}: {arg}"));
+}
+
+/// Default time budget of a solver in `aoc23 run`, generous enough for the slow brute forces.
+const RUN_TIMEOUT: u32 = 300;
+
+/// Solvers that answer sooner do not get a progress bar, it would only flicker.
+const BAR_AFTER: Duration = Duration::from_millis(500);
+
+/// Advent of Code started in 2015, a smaller number is a day from before the year argument.
+fn parse_year(arg: Option<&String>) -> Result<u32, String> {
+    let year = parse_num(arg, "year")?;
+    if year < 2015 {
+        return Err(format!(
+            "bad year: {year}, the year comes first, e.g. 2023 {year}"
+        ));
+    }
+    return Ok(year);
+}
+
+fn cmd_run(args: &[String]) -> Result<ExitCode, String> {
+    if args.first().map(|s| s.as_str()) == Some("--all") {
+        return cmd_run_all(&args[1..]);
+    }
+    let year = parse_year(args.first())?;
+    let day = parse_num(args.get(1), "day")?;
+    let part = parse_num(args.get(2), "part")?;
+    let solver = registry::find(year, day, part)
+        .ok_or(format!("{year} day {day} part {part} is not solved"))?;
+    let config = Config::load(Path::new(params::CONFIG_PATH)).map_err(|e| e.to_string())?;
+    let mut overrides = config.overrides(year, day, solver.params);
+    let (mut explain, mut fresh, mut timeout) = (false, false, RUN_TIMEOUT);
+    let mut algo = None;
+    let mut rest = args[3..].iter();
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--param" => {
+                let param = rest.next().ok_or("missing value for --param")?;
+                overrides.set_from_arg(param).map_err(|e| e.to_string())?;
+            }
+            "--explain" => explain = true,
+            "--fresh" => fresh = true,
+            "--timeout" => timeout = parse_num(rest.next(), "timeout")?,
+            "--algo" => algo = Some(rest.next().ok_or("missing value for --algo")?),
+            other => return Err(format!("unknown option: {other}")),
+        }
+    }
+    let solver = match algo {
+        Some(name) => solver.with_algo(name).map_err(|e| e.to_string())?,
+        None => *solver,
+    };
+    let path = registry::input_path(year, day);
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("input for day {day} is missing ({}): {e}", path.display()))?;
+    let mut cache = load_cache()?;
+    // explain needs the facts, a what-if run is not the real answer and the cache does not
+    // know the algorithm, all of them skip the cache
+    let cached = match fresh || explain || !overrides.is_empty() || algo.is_some() {
+        true => None,
+        false => cache.get(&solver, &content).cloned(),
+    };
+    if let Some(result) = cached {
+        println!("{year} day {day} part {part}: {result} (cached)");
+        return check_verified((year, day, part), &result);
+    }
+    let budget = Duration::from_secs(timeout.into());
+    let (result, facts, elapsed) = match run_watched(&solver, &content, &overrides, budget) {
+        Outcome::Solved {
+            answer,
+            facts,
+            elapsed,
+        } => (answer, facts, elapsed),
+        Outcome::Failed(e) => return Err(e.to_string()),
+        Outcome::TimedOut(_) => return Err(format!("no answer within {timeout}s, see --timeout")),
+    };
+    println!("{year} day {day} part {part}: {result}");
+    if explain {
+        match solver.explains {
+            true => print!("{}", facts.render()),
+            false => println!("  (this solver records no intermediate results)"),
+        }
+    }
+    if !overrides.is_empty() {
+        // a what-if run, neither the verified answer nor the runtime apply
+        println!("with {}", describe(&overrides));
+        return Ok(ExitCode::SUCCESS);
+    }
+    if let Some(name) = algo {
+        // the recorded runtime and the cached answer belong to the default algorithm
+        println!("with the {name} algorithm");
+        return check_verified((year, day, part), &result);
+    }
+    store::record_runtime(&store::runtimes_path(), (year, day, part), elapsed)
+        .map_err(|e| format!("cannot record runtime: {e}"))?;
+    cache.insert(&solver, &content, result.clone());
+    save_cache(&cache)?;
+    return check_verified((year, day, part), &result);
+}
+
+/// Run the solver within `budget`, with a progress bar on stderr once it takes a while.
+fn run_watched(solver: &Solver, content: &str, overrides: &Params, budget: Duration) -> Outcome {
+    // the bar would keep overwriting the last dumped line
+    let show = io::stderr().is_terminal() && dump::level() == Level::Quiet;
+    let mut drawn = false;
+    let outcome = runner::run(
+        solver,
+        content.to_owned(),
+        overrides.clone(),
+        budget,
+        |tick| {
+            if show && tick.elapsed >= BAR_AFTER {
+                let (year, day, part) = (solver.year, solver.day, solver.part);
+                eprint!("\r{year} day {day} part {part} {}", tick.render(30));
+                drawn = true;
+            }
+        },
+    );
+    if drawn {
+        // clear the bar so the answer starts on a clean line
+        eprint!("\r\x1b[K");
+    }
+    return outcome;
+}
+
+fn load_cache() -> Result<Cache, String> {
+    return Cache::load(&cache::path()).map_err(|e| format!("cannot read the cache: {e}"));
+}
+
+fn save_cache(cache: &Cache) -> Result<(), String> {
+    return cache
+        .save(&cache::path())
+        .map_err(|e| format!("cannot write the cache: {e}"));
+}
+
+fn check_verified(key: store::Key, result: &Answer) -> Result<ExitCode, String> {
+    let answers = store::load_answers(Path::new(store::ANSWERS_PATH))
+        .map_err(|e| format!("cannot read {}: {e}", store::ANSWERS_PATH))?;
+    match answers.get(&key) {
+        Some(expected) if expected != result => {
+            println!("WRONG, the verified answer is {expected}");
+            return Ok(ExitCode::FAILURE);
+        }
+        Some(_) => println!("matches the verified answer"),
+        None => {}
+    }
+    return Ok(ExitCode::SUCCESS);
+}
+
+/// Every solver of every year with an input, answers come from the cache unless `--fresh` is given.
+fn cmd_run_all(args: &[String]) -> Result<ExitCode, String> {
+    let (mut fresh, mut timeout) = (false, RUN_TIMEOUT);
+    let mut rest = args.iter();
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--fresh" => fresh = true,
+            "--timeout" => timeout = parse_num(rest.next(), "timeout")?,
+            other => return Err(format!("unknown option: {other}")),
+        }
+    }
+    let budget = Duration::from_secs(timeout.into());
+    let config = Config::load(Path::new(params::CONFIG_PATH)).map_err(|e| e.to_string())?;
+    let answers = store::load_answers(Path::new(store::ANSWERS_PATH))
+        .map_err(|e| format!("cannot read {}: {e}", store::ANSWERS_PATH))?;
+    let mut cache = load_cache()?;
+    let mut failed = false;
+    for solver in registry::SOLVERS {
+        let (year, day, part) = (solver.year, solver.day, solver.part);
+        if solver.is_stub() {
+            println!("{year} day {day} part {part}: not solved yet");
+            continue;
+        }
+        let Ok(content) = fs::read_to_string(registry::input_path(year, day)) else {
+            println!("{year} day {day} part {part}: no input");
+            continue;
+        };
+        let overrides = config.overrides(year, day, solver.params);
+        let cached = match fresh || !overrides.is_empty() {
+            true => None,
+            false => cache.get(solver, &content).cloned(),
+        };
+        let (result, how) = match cached {
+            Some(result) => (result, String::from("cached")),
+            None => {
+                let (result, elapsed) = match run_watched(solver, &content, &overrides, budget) {
+                    Outcome::Solved {
+                        answer, elapsed, ..
+                    } => (answer, elapsed),
+                    Outcome::Failed(e) => {
+                        println!("{year} day {day} part {part}: {e}");
+                        failed = true;
+                        continue;
+                    }
+                    Outcome::TimedOut(_) => {
+                        println!("{year} day {day} part {part}: no answer within {timeout}s");
+                        failed = true;
+                        continue;
+                    }
+                };
+                if overrides.is_empty() {
+                    store::record_runtime(&store::runtimes_path(), (year, day, part), elapsed)
+                        .map_err(|e| format!("cannot record runtime: {e}"))?;
+                    cache.insert(solver, &content, result.clone());
+                }
+                (result, status::format_duration(elapsed))
+            }
+        };
+        let verdict = match answers.get(&(year, day, part)) {
+            Some(expected) if *expected != result => {
+                failed = true;
+                format!(", WRONG, the verified answer is {expected}")
+            }
+            _ => String::new(),
+        };
+        println!("{year} day {day} part {part}: {result} ({how}){verdict}");
+    }
+    save_cache(&cache)?;
+    return Ok(if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    });
+}
+
+fn describe(params: &Params) -> String {
+    return params
+        .iter()
+        .map(|(name, value)| format!("{name}={value}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+}
+
+fn cmd_certify(args: &[String]) -> Result<ExitCode, String> {
+    let year = parse_year(args.first())?;
+    let day = parse_num(args.get(1), "day")?;
+    let part = parse_num(args.get(2), "part")?;
+    let solver = registry::find(year, day, part)
+        .ok_or(format!("{year} day {day} part {part} is not solved"))?;
+    let certifier = solver.certifier.as_ref().ok_or(format!(
+        "{year} day {day} part {part} gives no certificates"
+    ))?;
+    let path = registry::input_path(year, day);
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("input for day {day} is missing ({}): {e}", path.display()))?;
+    let (result, certificate) = certifier.certify(&content).map_err(|e| e.to_string())?;
+    println!("{year} day {day} part {part}: {result}");
+    println!("{certificate}");
+    certifier
+        .check(&content, &result, &certificate)
+        .map_err(|e| e.to_string())?;
+    println!("the certificate checks out");
+    return check_verified((year, day, part), &result);
+}
+
+/// Every algorithm of a solver on the real input, one line each with its answer and runtime.
+fn cmd_bench(args: &[String]) -> Result<ExitCode, String> {
+    let year = parse_year(args.first())?;
+    let day = parse_num(args.get(1), "day")?;
+    let part = parse_num(args.get(2), "part")?;
+    let timeout = match args.get(3).map(|s| s.as_str()) {
+        Some("--timeout") => parse_num(args.get(4), "timeout")?,
+        Some(other) => return Err(format!("unknown option: {other}")),
+        None => RUN_TIMEOUT,
+    };
+    let solver = registry::find(year, day, part)
+        .ok_or(format!("{year} day {day} part {part} is not solved"))?;
+    let config = Config::load(Path::new(params::CONFIG_PATH)).map_err(|e| e.to_string())?;
+    let overrides = config.overrides(year, day, solver.params);
+    let path = registry::input_path(year, day);
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("input for day {day} is missing ({}): {e}", path.display()))?;
+    let budget = Duration::from_secs(timeout.into());
+    let names = solver.algo_names();
+    let count = names.len();
+    let width = names.iter().map(|name| name.len()).max().unwrap_or(0);
+    let width = width.max("parse".len());
+    let mut answers = vec![];
+    let mut default_elapsed = None;
+    for name in names {
+        let algo = solver.with_algo(name).map_err(|e| e.to_string())?;
+        let line = match run_watched(&algo, &content, &overrides, budget) {
+            Outcome::Solved {
+                answer, elapsed, ..
+            } => {
+                let line = format!("{answer} ({}ms)", elapsed.as_millis());
+                if name == registry::DEFAULT_ALGO {
+                    default_elapsed = Some(elapsed);
+                }
+                answers.push(answer);
+                line
+            }
+            Outcome::Failed(e) => format!("ERROR {e}"),
+            Outcome::TimedOut(budget) => format!("TIMEOUT >{}s", budget.as_secs()),
+        };
+        println!("{name:width$}  {line}");
+    }
+    if let Some(parse) = time_parse(solver, &content)? {
+        let ms = parse.as_secs_f64() * 1000.0;
+        let share = match default_elapsed {
+            Some(elapsed) if !elapsed.is_zero() => {
+                let percent = 100.0 * parse.as_secs_f64() / elapsed.as_secs_f64();
+                format!(", {percent:.1}% of the default run")
+            }
+            _ => String::new(),
+        };
+        println!("{:width$}  {ms:.3}ms{share}", "parse");
+    }
+    if answers.windows(2).any(|pair| pair[0] != pair[1]) {
+        println!("the algorithms disagree");
+        return Ok(ExitCode::FAILURE);
+    }
+    return Ok(match answers.len() == count {
+        true => ExitCode::SUCCESS,
+        false => ExitCode::FAILURE,
+    });
+}
+
+/// Fastest of a few parses of `content`, `None` if the solver does not register its parser.
+fn time_parse(solver: &Solver, content: &str) -> Result<Option<Duration>, String> {
+    const RUNS: usize = 5;
+    let mut fastest = None;
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        match solver.validate(content) {
+            None => return Ok(None),
+            Some(result) => result.map_err(|e| e.to_string())?,
+        }
+        let elapsed = start.elapsed();
+        fastest = Some(fastest.map_or(elapsed, |best: Duration| best.min(elapsed)));
+    }
+    return Ok(fastest);
+}
+
+fn cmd_inspect(args: &[String]) -> Result<ExitCode, String> {
+    let year = parse_year(args.first())?;
+    let day = parse_num(args.get(1), "day")?;
+    if registry::parts_of(year, day).is_empty() {
+        return Err(format!("{year} day {day} is not solved"));
+    }
+    let path = registry::input_path(year, day);
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("input for day {day} is missing ({}): {e}", path.display()))?;
+    let facts = registry::inspect(year, day, &content)
+        .ok_or(format!("{year} day {day} has nothing to inspect"))?
+        .map_err(|e| e.to_string())?;
+    println!("{year} day {day}");
+    print!("{}", facts.render());
+    return Ok(ExitCode::SUCCESS);
+}
+
+fn cmd_batch(args: &[String]) -> Result<ExitCode, String> {
+    let year = parse_year(args.first())?;
+    let day = parse_num(args.get(1), "day")?;
+    let dir = args.get(2).ok_or("missing input directory")?;
+    let timeout = match args.get(3).map(|s| s.as_str()) {
+        Some("--timeout") => parse_num(args.get(4), "timeout")?,
+        Some(other) => return Err(format!("unknown option: {other}")),
+        None => 10,
+    };
+    if registry::parts_of(year, day).is_empty() {
+        return Err(format!("{year} day {day} is not solved"));
+    }
+    // panics are reported in the table, keep stderr quiet
+    panic::set_hook(Box::new(|_| {}));
+    let rows = batch::run(
+        year,
+        day,
+        Path::new(dir),
+        Duration::from_secs(timeout.into()),
+    )
+    .map_err(|e| format!("cannot read {dir}: {e}"))?;
+    print!("{}", batch::render(&rows));
+    let flagged = rows.iter().flat_map(|r| &r.cells).any(|c| c.flagged());
+    return Ok(if flagged {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    });
+}
+
+fn cmd_status() -> Result<ExitCode, String> {
+    let answers = store::load_answers(Path::new(store::ANSWERS_PATH))
+        .map_err(|e| format!("cannot read {}: {e}", store::ANSWERS_PATH))?;
+    let runtimes = store::load_runtimes(&store::runtimes_path())
+        .map_err(|e| format!("cannot read runtimes: {e}"))?;
+    print!("{}", status::render(&status::collect(&answers, &runtimes)));
+    return Ok(ExitCode::SUCCESS);
+}
+
+fn cmd_serve(args: &[String]) -> Result<ExitCode, String> {
+    let (mut port, mut timeout) = (2023, 10);
+    let mut rest = args.iter();
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--port" => port = parse_num(rest.next(), "port")?,
+            "--timeout" => timeout = parse_num(rest.next(), "timeout")?,
+            other => return Err(format!("unknown option: {other}")),
+        }
+    }
+    let port = u16::try_from(port).map_err(|_| format!("bad port: {port}"))?;
+    // localhost only, this is not meant to face the internet
+    let listener = TcpListener::bind(("127.0.0.1", port))
+        .map_err(|e| format!("cannot listen on port {port}: {e}"))?;
+    // panics are reported in the responses, keep stderr quiet
+    panic::set_hook(Box::new(|_| {}));
+    println!("listening on http://127.0.0.1:{port}");
+    server::serve(listener, Duration::from_secs(timeout.into())).map_err(|e| e.to_string())?;
+    return Ok(ExitCode::SUCCESS);
+}
+
+fn cmd_new(args: &[String]) -> Result<ExitCode, String> {
+    let year = parse_year(args.first())?;
+    let day = parse_num(args.get(1), "day")?;
+    let changed = scaffold::new_day(Path::new("."), year, day).map_err(|e| e.to_string())?;
+    for path in changed {
+        println!("{}", path.display());
+    }
+    return Ok(ExitCode::SUCCESS);
+}
+
+/// Removes `-v`, `-vv` and so on from `args`, the level is how many `v`s there were.
+fn take_verbosity(args: &mut Vec<String>) -> Level {
+    let mut count = 0;
+    args.retain(|arg| {
+        let vs = arg.strip_prefix('-').unwrap_or_default();
+        if vs.is_empty() || vs.chars().any(|c| c != 'v') {
+            return true;
+        }
+        count += vs.len();
+        return false;
+    });
+    return Level::from_count(count);
+}
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    dump::set_level(take_verbosity(&mut args));
+    let result = match args.first().map(|s| s.as_str()) {
+        Some("run") => cmd_run(&args[1..]),
+        Some("certify") => cmd_certify(&args[1..]),
+        Some("bench") => cmd_bench(&args[1..]),
+        Some("inspect") => cmd_inspect(&args[1..]),
+        Some("batch") => cmd_batch(&args[1..]),
+        Some("status") => cmd_status(),
+        Some("serve") => cmd_serve(&args[1..]),
+        Some("new") => cmd_new(&args[1..]),
+        _ => Err(USAGE.to_string()),
+    };
+    match result {
+        Ok(code) => code,
+        Err(msg) => {
+            eprintln!("{msg}");
+            ExitCode::from(2)
+        }
+    }
+}
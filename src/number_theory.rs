@@ -0,0 +1,152 @@
+/// Overflow-checked gcd and lcm, for `i64` and `i128`.
+pub trait Divisors: Sized {
+    /// Greatest common divisor, never negative. `None` if it does not fit, as for `gcd(MIN, 0)`.
+    fn checked_gcd(self, other: Self) -> Option<Self>;
+    /// Least common multiple, never negative. `None` if it does not fit.
+    fn checked_lcm(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! divisors {
+    ($t:ty) => {
+        impl Divisors for $t {
+            fn checked_gcd(self, other: $t) -> Option<$t> {
+                let (mut a, mut b) = (self, other);
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                return a.checked_abs();
+            }
+
+            fn checked_lcm(self, other: $t) -> Option<$t> {
+                if self == 0 || other == 0 {
+                    return Some(0);
+                }
+                // divide first, a * b can overflow even when the lcm fits
+                let gcd = self.checked_gcd(other)?;
+                return (self / gcd).checked_mul(other)?.checked_abs();
+            }
+        }
+    };
+}
+divisors!(i64);
+divisors!(i128);
+
+/// `(g, x, y)` with `a * x + b * y == g`, where `g` is the gcd of `a` and `b`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    // https://en.wikipedia.org/wiki/Extended_Euclidean_algorithm
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        return (-old_r, -old_x, -old_y);
+    }
+    return (old_r, old_x, old_y);
+}
+
+/// `x` in `0..m` with `a * x ≡ 1 (mod m)`, `None` unless `a` and `m` are coprime.
+pub fn mod_inverse(a: i128, m: i128) -> Option<i128> {
+    assert!(m > 0, "modulus must be positive, got {m}");
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    return (g == 1).then(|| x.rem_euclid(m));
+}
+
+/// Smallest `x >= 0` and the modulus `M` such that every `x' ≡ x (mod M)` satisfies all
+/// `x' ≡ residue (mod modulus)`. The moduli do not have to be coprime.
+/// `None` when the congruences contradict each other or `M` does not fit an `i128`.
+pub fn crt(congruences: &[(i128, i128)]) -> Option<(i128, i128)> {
+    // https://en.wikipedia.org/wiki/Chinese_remainder_theorem#Generalization_to_non-coprime_moduli
+    let (mut x, mut m) = (0, 1);
+    for &(residue, modulus) in congruences {
+        assert!(modulus > 0, "modulus must be positive, got {modulus}");
+        let residue = residue.rem_euclid(modulus);
+        // x + m * k ≡ residue (mod modulus), solve for k
+        let g = m.checked_gcd(modulus)?;
+        let diff = residue - x;
+        if diff % g != 0 {
+            return None;
+        }
+        let step = modulus / g;
+        let k = if step == 1 {
+            0
+        } else {
+            let inverse = mod_inverse(m / g, step)?;
+            (diff / g).rem_euclid(step).checked_mul(inverse)? % step
+        };
+        let lcm = m.checked_lcm(modulus)?;
+        x = m.checked_mul(k)?.checked_add(x)?.rem_euclid(lcm);
+        m = lcm;
+    }
+    return Some((x, m));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(8i64.checked_gcd(12), Some(4));
+        assert_eq!(12i64.checked_gcd(8), Some(4));
+        assert_eq!(1i64.checked_gcd(3), Some(1));
+        assert_eq!(20i64.checked_gcd(4), Some(4));
+        assert_eq!(11i64.checked_gcd(11), Some(11));
+        assert_eq!((-8i64).checked_gcd(12), Some(4));
+        assert_eq!(0i64.checked_gcd(0), Some(0));
+        assert_eq!(i64::MIN.checked_gcd(0), None);
+        assert_eq!((i64::MIN as i128).checked_gcd(0), Some(1 << 63));
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(8i64.checked_lcm(12), Some(24));
+        assert_eq!(12i64.checked_lcm(8), Some(24));
+        assert_eq!(1i64.checked_lcm(3), Some(3));
+        assert_eq!(20i64.checked_lcm(4), Some(20));
+        assert_eq!(11i64.checked_lcm(11), Some(11));
+        assert_eq!(5i64.checked_lcm(7), Some(35));
+        assert_eq!((-4i64).checked_lcm(6), Some(12));
+        assert_eq!(0i64.checked_lcm(7), Some(0));
+        // a * b overflows but the lcm fits
+        let big = 1i64 << 40;
+        assert_eq!(big.checked_lcm(big * 2), Some(big * 2));
+        assert_eq!((big + 1).checked_lcm(big * 2), None);
+        assert_eq!(
+            ((big + 1) as i128).checked_lcm((big * 2) as i128),
+            Some((big as i128 + 1) * (big as i128 * 2))
+        );
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [(240, 46), (46, 240), (-240, 46), (7, 0), (0, 7), (17, 17)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, (a as i64).checked_gcd(b as i64).unwrap() as i128);
+            assert_eq!(a * x + b * y, g, "{a} {b}");
+        }
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(5, 1), Some(0));
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // non-coprime moduli
+        assert_eq!(crt(&[(1, 3), (0, 2), (4, 6)]), Some((4, 6)));
+        assert_eq!(crt(&[(3, 4), (1, 6)]), Some((7, 12)));
+        assert_eq!(crt(&[(0, 4), (1, 6)]), None);
+        // zero offsets are just the lcm
+        assert_eq!(crt(&[(0, 4), (0, 6)]), Some((0, 12)));
+        assert_eq!(crt(&[(-1, 5)]), Some((4, 5)));
+        let big = 1i128 << 100;
+        assert_eq!(crt(&[(0, big), (0, big - 1)]), None);
+    }
+}
@@ -0,0 +1,258 @@
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+use crate::{registry, Error};
+
+pub const CONFIG_PATH: &str = "aoc23.toml";
+
+/// A puzzle constant a solver lets you change, e.g. the number of steps.
+#[derive(Debug)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: i64,
+    /// Smallest value that makes sense, anything below is rejected.
+    pub min: i64,
+    /// Largest value that makes sense, anything above is rejected.
+    pub max: i64,
+    pub help: &'static str,
+}
+
+/// Parameter values by name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, i64>,
+}
+impl Params {
+    pub fn new() -> Self {
+        return Params::default();
+    }
+
+    pub fn set(&mut self, name: &str, value: i64) {
+        self.values.insert(name.to_string(), value);
+    }
+
+    /// Value of a declared parameter, only valid on params made by [`Params::resolve`].
+    pub fn get<T: TryFrom<i64>>(&self, name: &str) -> Result<T, Error> {
+        let value = *self
+            .values
+            .get(name)
+            .unwrap_or_else(|| panic!("parameter {name:?} is not declared"));
+        return T::try_from(value)
+            .map_err(|_| Error::Param(format!("{name} is out of range: {value}")));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.values.is_empty();
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, i64)> {
+        return self
+            .values
+            .iter()
+            .map(|(name, &value)| (name.as_str(), value));
+    }
+
+    /// Parse a `name=value` override as given on the command line.
+    pub fn set_from_arg(&mut self, arg: &str) -> Result<(), Error> {
+        let (name, value) = arg
+            .split_once('=')
+            .ok_or_else(|| Error::Param(format!("expected `name=value`: {arg:?}")))?;
+        let value = value
+            .parse()
+            .map_err(|_| Error::Param(format!("{name} must be an integer: {value:?}")))?;
+        self.set(name, value);
+        return Ok(());
+    }
+
+    /// Fill in the defaults of `declared` and apply `overrides` on top.
+    pub fn resolve(declared: &[Param], overrides: &Params) -> Result<Params, Error> {
+        for name in overrides.values.keys() {
+            if !declared.iter().any(|param| param.name == name) {
+                return Err(Error::Param(format!("unknown parameter {name:?}")));
+            }
+        }
+        let mut params = Params::new();
+        for param in declared {
+            let value = overrides
+                .values
+                .get(param.name)
+                .copied()
+                .unwrap_or(param.default);
+            if value < param.min {
+                return Err(Error::Param(format!(
+                    "{} must be at least {}, got {value}",
+                    param.name, param.min
+                )));
+            }
+            if value > param.max {
+                return Err(Error::Param(format!(
+                    "{} must be at most {}, got {value}",
+                    param.name, param.max
+                )));
+            }
+            params.set(param.name, value);
+        }
+        return Ok(params);
+    }
+}
+
+/// Parameter overrides from `aoc23.toml`, one table per year and day:
+/// ```toml
+/// [2023.day21]
+/// steps = 100
+/// ```
+/// A top-level `[dayNN]` table, from before there were several years, is 2023.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Config {
+    days: BTreeMap<(u32, u32), Params>,
+}
+impl Config {
+    pub fn parse(content: &str) -> Result<Config, Error> {
+        let table = content
+            .parse::<toml::Table>()
+            .map_err(|e| Error::Param(e.message().to_string()))?;
+        let mut config = Config::default();
+        for (key, value) in table {
+            if key.starts_with("day") {
+                config.add_day(2023, &key, value)?;
+                continue;
+            }
+            let (Ok(year), toml::Value::Table(days)) = (key.parse::<u32>(), value) else {
+                let msg = format!("expected a [YYYY.dayNN] table, got {key:?}");
+                return Err(Error::Param(msg));
+            };
+            for (day, value) in days {
+                config.add_day(year, &day, value)?;
+            }
+        }
+        return Ok(config);
+    }
+
+    fn add_day(&mut self, year: u32, key: &str, value: toml::Value) -> Result<(), Error> {
+        let day = key
+            .strip_prefix("day")
+            .and_then(|day| day.parse::<u32>().ok())
+            .ok_or_else(|| Error::Param(format!("expected a [YYYY.dayNN] table, got {key:?}")))?;
+        let toml::Value::Table(values) = value else {
+            return Err(Error::Param(format!("{year}.{key} must be a table")));
+        };
+        let declared = registry::parts_of(year, day)
+            .into_iter()
+            .flat_map(|solver| solver.params)
+            .collect::<Vec<_>>();
+        let params = self.days.entry((year, day)).or_default();
+        for (name, value) in values {
+            if !declared.iter().any(|param| param.name == name) {
+                let msg = format!("{year}.{key} has no parameter {name:?}");
+                return Err(Error::Param(msg));
+            }
+            let toml::Value::Integer(value) = value else {
+                return Err(Error::Param(format!(
+                    "{year}.{key}.{name} must be an integer"
+                )));
+            };
+            params.set(&name, value);
+        }
+        return Ok(());
+    }
+
+    /// Read the config file, a missing file is an empty config.
+    pub fn load(path: &Path) -> Result<Config, Error> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Config::default()),
+            Err(e) => return Err(Error::Param(format!("cannot read {}: {e}", path.display()))),
+        };
+        return Config::parse(&content);
+    }
+
+    /// The overrides of `day` that `declared` knows about, the table is shared by both parts.
+    pub fn overrides(&self, year: u32, day: u32, declared: &[Param]) -> Params {
+        let mut params = Params::new();
+        let table = self.days.get(&(year, day));
+        for (name, value) in table.into_iter().flat_map(|p| p.iter()) {
+            if declared.iter().any(|param| param.name == name) {
+                params.set(name, value);
+            }
+        }
+        return params;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DECLARED: &[Param] = &[
+        Param {
+            name: "steps",
+            default: 64,
+            min: 0,
+            max: i64::MAX,
+            help: "",
+        },
+        Param {
+            name: "copies",
+            default: 5,
+            min: 1,
+            max: 100,
+            help: "",
+        },
+    ];
+
+    #[test]
+    fn test_resolve() {
+        let params = Params::resolve(DECLARED, &Params::new()).unwrap();
+        assert_eq!(params.get::<i64>("steps"), Ok(64));
+        assert_eq!(params.get::<usize>("copies"), Ok(5));
+
+        let mut overrides = Params::new();
+        overrides.set_from_arg("steps=6").unwrap();
+        let params = Params::resolve(DECLARED, &overrides).unwrap();
+        assert_eq!(params.get::<i64>("steps"), Ok(6));
+
+        overrides.set("steps", i64::MAX);
+        let params = Params::resolve(DECLARED, &overrides).unwrap();
+        assert!(params.get::<i32>("steps").is_err());
+
+        overrides.set("copies", 0);
+        assert!(Params::resolve(DECLARED, &overrides).is_err());
+        overrides.set("copies", 101);
+        assert!(Params::resolve(DECLARED, &overrides).is_err());
+        overrides.set("walls", 1);
+        assert!(Params::resolve(&DECLARED[..1], &overrides).is_err());
+    }
+
+    #[test]
+    fn test_set_from_arg() {
+        let mut params = Params::new();
+        assert!(params.set_from_arg("steps").is_err());
+        assert!(params.set_from_arg("steps=many").is_err());
+        assert!(params.set_from_arg("steps=-3").is_ok());
+    }
+
+    #[test]
+    fn test_config() {
+        let config =
+            Config::parse("# what if\n[2023.day21]\nsteps = 100\n\n[2023.day02]\nred = 20\n")
+                .unwrap();
+        let day21 = registry::find(2023, 21, 1).unwrap().params;
+        assert_eq!(
+            config.overrides(2023, 21, day21).get::<i64>("steps"),
+            Ok(100)
+        );
+        assert!(config.overrides(2024, 21, day21).is_empty());
+        // the day02 table only applies to the part that declares it
+        assert!(config.overrides(2023, 2, &[]).is_empty());
+        // the layout from before there were several years
+        let legacy = Config::parse("[day21]\nsteps = 100").unwrap();
+        assert_eq!(legacy, Config::parse("[2023.day21]\nsteps = 100").unwrap());
+
+        assert!(Config::parse("[2023.day21]\nwalls = 1").is_err());
+        assert!(Config::parse("[2023.day21]\nsteps = \"many\"").is_err());
+        assert!(Config::parse("[2022.day21]\nsteps = 1").is_err());
+        assert!(Config::parse("[2023.twentyone]\nsteps = 1").is_err());
+        assert!(Config::parse("[twentyone]\nsteps = 1").is_err());
+        assert!(Config::parse("steps = 1").is_err());
+        assert!(Config::parse("[day21").is_err());
+    }
+}
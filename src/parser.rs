@@ -0,0 +1,474 @@
+//! Small parser combinators for puzzle inputs.
+//!
+//! A parser is any `Fn(Input) -> PResult<T>`, built from the functions below and run
+//! with [`parse_all`]. Failures remember where they happened and turn into an
+//! [`Error::Parse`] pointing at the line and column.
+//!
+//! ```
+//! use aoc23::parser::{int, key_value, list, pair, parse_all, space1, tag, terminated};
+//!
+//! let numbers = |key| key_value(key, list(int::<i64>(), space1()));
+//! let grammar = pair(terminated(numbers("Time"), tag("\n")), numbers("Distance"));
+//! let (times, distances) = parse_all("Time: 7 15\nDistance: 9 40", grammar).unwrap();
+//! assert_eq!((times, distances), (vec![7, 15], vec![9, 40]));
+//! ```
+use std::{fmt::Display, str::FromStr};
+
+use crate::Error;
+
+/// The text a parser has left, and where it starts in the whole input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Input<'a> {
+    pub rest: &'a str,
+    pub offset: usize,
+}
+impl<'a> Input<'a> {
+    pub fn new(text: &'a str) -> Input<'a> {
+        return Input {
+            rest: text,
+            offset: 0,
+        };
+    }
+
+    /// The first `n` bytes, and the input after them.
+    fn advance(self, n: usize) -> (&'a str, Input<'a>) {
+        let (taken, rest) = self.rest.split_at(n);
+        return (
+            taken,
+            Input {
+                rest,
+                offset: self.offset + n,
+            },
+        );
+    }
+}
+
+/// Byte range `start..end` of the whole input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// A parsed value and the text it came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Spanned<T> {
+    pub value: T,
+    pub span: Span,
+}
+
+/// What a parser expected, and the offset where it did not find it.
+/// A committed failure is final, [`or`], [`opt`] and [`list`] do not try anything else.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    pub offset: usize,
+    pub expected: String,
+    pub committed: bool,
+}
+impl Failure {
+    pub fn new(input: Input, expected: impl Into<String>) -> Failure {
+        return Failure {
+            offset: input.offset,
+            expected: expected.into(),
+            committed: false,
+        };
+    }
+
+    /// The failure as an [`Error`], with the line and column in `content`.
+    pub fn into_error(self, content: &str) -> Error {
+        let rest = &content[self.offset..];
+        let found = match rest.lines().next() {
+            Some(line) if !line.is_empty() => format!("{:?}", truncate(line)),
+            _ if rest.is_empty() => String::from("end of input"),
+            _ => String::from("end of line"),
+        };
+        let msg = format!("expected {}, found {found}", self.expected);
+        return error_at(content, self.offset, msg);
+    }
+}
+
+fn truncate(text: &str) -> &str {
+    return match text.char_indices().nth(20) {
+        Some((end, _)) => &text[..end],
+        None => text,
+    };
+}
+
+/// An [`Error::Parse`] for `msg` at byte `offset` of `content`, as `line:column: msg`.
+/// For checks done after parsing, with the offset taken from a [`Span`].
+pub fn error_at(content: &str, offset: usize, msg: impl Display) -> Error {
+    let before = &content[..offset];
+    let line = before.matches('\n').count() + 1;
+    let column = before.len() - before.rfind('\n').map_or(0, |i| i + 1) + 1;
+    return Error::parse(format!("{line}:{column}: {msg}"));
+}
+
+pub type PResult<'a, T> = Result<(T, Input<'a>), Failure>;
+
+pub trait Parser<'a, T> {
+    fn parse(&self, input: Input<'a>) -> PResult<'a, T>;
+}
+impl<'a, T, F> Parser<'a, T> for F
+where
+    F: Fn(Input<'a>) -> PResult<'a, T>,
+{
+    fn parse(&self, input: Input<'a>) -> PResult<'a, T> {
+        return self(input);
+    }
+}
+
+/// Run `parser` over all of `content`. Only trailing whitespace may be left over.
+pub fn parse_all<'a, T>(content: &'a str, parser: impl Parser<'a, T>) -> Result<T, Error> {
+    let (value, rest) = parser
+        .parse(Input::new(content))
+        .map_err(|failure| failure.into_error(content))?;
+    if !rest.rest.trim_end().is_empty() {
+        return Err(Failure::new(rest, "end of input").into_error(content));
+    }
+    return Ok(value);
+}
+
+/// Exactly `expected`.
+pub fn tag<'a>(expected: &'static str) -> impl Fn(Input<'a>) -> PResult<'a, &'a str> {
+    return move |input: Input<'a>| {
+        if !input.rest.starts_with(expected) {
+            return Err(Failure::new(input, format!("{expected:?}")));
+        }
+        return Ok(input.advance(expected.len()));
+    };
+}
+
+/// The value paired with whichever of the `choices` the input starts with, tried in order.
+pub fn one_of<'a, T: Clone, const N: usize>(
+    choices: [(&'static str, T); N],
+) -> impl Fn(Input<'a>) -> PResult<'a, T> {
+    return move |input: Input<'a>| {
+        for (expected, value) in &choices {
+            if input.rest.starts_with(expected) {
+                return Ok((value.clone(), input.advance(expected.len()).1));
+            }
+        }
+        let names: Vec<_> = choices.iter().map(|(expected, _)| *expected).collect();
+        return Err(Failure::new(input, format!("one of {names:?}")));
+    };
+}
+
+/// Everything that is left, for sections a solver does not need.
+pub fn rest<'a>() -> impl Fn(Input<'a>) -> PResult<'a, &'a str> {
+    return |input: Input<'a>| Ok(input.advance(input.rest.len()));
+}
+
+/// One or more chars matching `pred`, `what` names them in errors.
+pub fn take_while1<'a>(
+    what: &'static str,
+    pred: impl Fn(char) -> bool,
+) -> impl Fn(Input<'a>) -> PResult<'a, &'a str> {
+    return move |input: Input<'a>| {
+        let n = input.rest.find(|c| !pred(c)).unwrap_or(input.rest.len());
+        if n == 0 {
+            return Err(Failure::new(input, what));
+        }
+        return Ok(input.advance(n));
+    };
+}
+
+/// A run of ASCII letters and digits, such as a module or workflow name.
+pub fn word<'a>() -> impl Fn(Input<'a>) -> PResult<'a, &'a str> {
+    return take_while1("a name", |c| c.is_ascii_alphanumeric());
+}
+
+pub fn digits<'a>() -> impl Fn(Input<'a>) -> PResult<'a, &'a str> {
+    return take_while1("digits", |c| c.is_ascii_digit());
+}
+
+/// Zero or more spaces.
+pub fn spaces<'a>() -> impl Fn(Input<'a>) -> PResult<'a, ()> {
+    return |input: Input<'a>| {
+        let n = input.rest.len() - input.rest.trim_start_matches(' ').len();
+        return Ok(((), input.advance(n).1));
+    };
+}
+
+/// One or more spaces.
+pub fn space1<'a>() -> impl Fn(Input<'a>) -> PResult<'a, ()> {
+    return map(take_while1("a space", |c| c == ' '), |_| ());
+}
+
+/// A decimal integer with an optional `-`, which has to fit `T`.
+pub fn int<'a, T: FromStr>() -> impl Fn(Input<'a>) -> PResult<'a, T> {
+    return |input: Input<'a>| {
+        let sign = usize::from(input.rest.starts_with('-'));
+        let n = input.rest[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(input.rest.len() - sign);
+        if n == 0 {
+            return Err(Failure::new(input, "a number"));
+        }
+        let (text, rest) = input.advance(sign + n);
+        return match text.parse() {
+            Ok(value) => Ok((value, rest)),
+            Err(_) => Err(Failure::new(input, "a smaller number")),
+        };
+    };
+}
+
+/// One or more integers separated by spaces.
+pub fn ints<'a, T: FromStr>() -> impl Fn(Input<'a>) -> PResult<'a, Vec<T>> {
+    return list(int(), space1());
+}
+
+pub fn map<'a, T, U>(
+    parser: impl Parser<'a, T>,
+    f: impl Fn(T) -> U,
+) -> impl Fn(Input<'a>) -> PResult<'a, U> {
+    return move |input: Input<'a>| {
+        let (value, rest) = parser.parse(input)?;
+        return Ok((f(value), rest));
+    };
+}
+
+/// Like [`map`] for conversions that can fail, the error is reported where `parser` started.
+/// The text had the right shape, so a bad value is a committed failure.
+pub fn try_map<'a, T, U>(
+    parser: impl Parser<'a, T>,
+    f: impl Fn(T) -> Result<U, String>,
+) -> impl Fn(Input<'a>) -> PResult<'a, U> {
+    return move |input: Input<'a>| {
+        let (value, rest) = parser.parse(input)?;
+        return match f(value) {
+            Ok(value) => Ok((value, rest)),
+            Err(expected) => Err(Failure {
+                committed: true,
+                ..Failure::new(input, expected)
+            }),
+        };
+    };
+}
+
+/// `value` whenever `parser` matches.
+pub fn value<'a, T: Clone, U>(
+    parser: impl Parser<'a, U>,
+    value: T,
+) -> impl Fn(Input<'a>) -> PResult<'a, T> {
+    return map(parser, move |_| value.clone());
+}
+
+pub fn pair<'a, A, B>(
+    a: impl Parser<'a, A>,
+    b: impl Parser<'a, B>,
+) -> impl Fn(Input<'a>) -> PResult<'a, (A, B)> {
+    return move |input: Input<'a>| {
+        let (x, input) = a.parse(input)?;
+        let (y, input) = b.parse(input)?;
+        return Ok(((x, y), input));
+    };
+}
+
+/// `parser` after `prefix`, keeping only the former.
+pub fn preceded<'a, P, T>(
+    prefix: impl Parser<'a, P>,
+    parser: impl Parser<'a, T>,
+) -> impl Fn(Input<'a>) -> PResult<'a, T> {
+    return map(pair(prefix, parser), |(_, value)| value);
+}
+
+/// `parser` followed by `suffix`, keeping only the former.
+pub fn terminated<'a, T, S>(
+    parser: impl Parser<'a, T>,
+    suffix: impl Parser<'a, S>,
+) -> impl Fn(Input<'a>) -> PResult<'a, T> {
+    return map(pair(parser, suffix), |(value, _)| value);
+}
+
+pub fn delimited<'a, O, T, C>(
+    open: impl Parser<'a, O>,
+    parser: impl Parser<'a, T>,
+    close: impl Parser<'a, C>,
+) -> impl Fn(Input<'a>) -> PResult<'a, T> {
+    return preceded(open, terminated(parser, close));
+}
+
+/// `a`, or `b` from the same place when `a` fails.
+pub fn or<'a, T>(
+    a: impl Parser<'a, T>,
+    b: impl Parser<'a, T>,
+) -> impl Fn(Input<'a>) -> PResult<'a, T> {
+    return move |input: Input<'a>| {
+        let first = match a.parse(input) {
+            Ok(found) => return Ok(found),
+            Err(failure) if failure.committed => return Err(failure),
+            Err(failure) => failure,
+        };
+        return b.parse(input).map_err(|second| {
+            // the alternative that got further is the more useful message
+            if first.offset > second.offset {
+                first
+            } else {
+                second
+            }
+        });
+    };
+}
+
+/// `None` instead of failing.
+pub fn opt<'a, T>(parser: impl Parser<'a, T>) -> impl Fn(Input<'a>) -> PResult<'a, Option<T>> {
+    return move |input: Input<'a>| {
+        return match parser.parse(input) {
+            Ok((value, rest)) => Ok((Some(value), rest)),
+            Err(failure) if failure.committed => Err(failure),
+            Err(_) => Ok((None, input)),
+        };
+    };
+}
+
+/// One or more `item`s with `sep` between them.
+/// A `sep` that is not followed by the start of another item is left unparsed.
+pub fn list<'a, T, S>(
+    item: impl Parser<'a, T>,
+    sep: impl Parser<'a, S>,
+) -> impl Fn(Input<'a>) -> PResult<'a, Vec<T>> {
+    return move |input: Input<'a>| {
+        let (first, mut input) = item.parse(input)?;
+        let mut items = vec![first];
+        while let Ok((_, after_sep)) = sep.parse(input) {
+            match item.parse(after_sep) {
+                Ok((next, rest)) => {
+                    items.push(next);
+                    input = rest;
+                }
+                // an item that got somewhere is broken, not the end of the list
+                Err(failure) if failure.committed || failure.offset > after_sep.offset => {
+                    return Err(failure)
+                }
+                Err(_) => break,
+            }
+        }
+        return Ok((items, input));
+    };
+}
+
+/// `item` on each of one or more lines.
+pub fn lines<'a, T>(item: impl Parser<'a, T>) -> impl Fn(Input<'a>) -> PResult<'a, Vec<T>> {
+    return list(item, tag("\n"));
+}
+
+/// One or more `section`s separated by a blank line.
+pub fn sections<'a, T>(section: impl Parser<'a, T>) -> impl Fn(Input<'a>) -> PResult<'a, Vec<T>> {
+    return list(section, tag("\n\n"));
+}
+
+/// `key: value`, with any number of spaces after the colon.
+pub fn key_value<'a, T>(
+    key: &'static str,
+    value: impl Parser<'a, T>,
+) -> impl Fn(Input<'a>) -> PResult<'a, T> {
+    return preceded(pair(tag(key), pair(tag(":"), spaces())), value);
+}
+
+/// Keep where `parser` matched, for errors found after parsing.
+pub fn spanned<'a, T>(parser: impl Parser<'a, T>) -> impl Fn(Input<'a>) -> PResult<'a, Spanned<T>> {
+    return move |input: Input<'a>| {
+        let (value, rest) = parser.parse(input)?;
+        let span = Span {
+            start: input.offset,
+            end: rest.offset,
+        };
+        return Ok((Spanned { value, span }, rest));
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(result: Result<impl std::fmt::Debug, Error>) -> String {
+        return match result.unwrap_err() {
+            Error::Parse(msg) => msg,
+            other => panic!("unexpected {other:?}"),
+        };
+    }
+
+    #[test]
+    fn test_int() {
+        assert_eq!(parse_all("-42", int::<i64>()), Ok(-42));
+        assert_eq!(parse_all("7\n", int::<u8>()), Ok(7));
+        assert_eq!(
+            message(parse_all("300", int::<u8>())),
+            "1:1: expected a smaller number, found \"300\""
+        );
+        assert_eq!(
+            message(parse_all("-", int::<i64>())),
+            "1:1: expected a number, found \"-\""
+        );
+        assert_eq!(
+            message(parse_all("4x", int::<i64>())),
+            "1:2: expected end of input, found \"x\""
+        );
+    }
+
+    #[test]
+    fn test_list() {
+        let numbers = list(int::<i32>(), pair(tag(","), spaces()));
+        assert_eq!(parse_all("1, 2,3", &numbers), Ok(vec![1, 2, 3]));
+        // a trailing separator is left for whoever comes next
+        let trailing = terminated(list(int::<i32>(), tag(",")), tag(",;"));
+        assert_eq!(parse_all("1,2,;", trailing), Ok(vec![1, 2]));
+        assert!(parse_all("", &numbers).is_err());
+    }
+
+    #[test]
+    fn test_sections() {
+        let block = pair(
+            terminated(word(), tag(":\n")),
+            lines(list(int::<i32>(), space1())),
+        );
+        let content = "a:\n1 2\n3\n\nb:\n4\n";
+        let parsed = parse_all(content, sections(block)).unwrap();
+        assert_eq!(
+            parsed,
+            vec![("a", vec![vec![1, 2], vec![3]]), ("b", vec![vec![4]])]
+        );
+        let msg = message(parse_all(
+            "a:\n1\n\nb:\nx",
+            sections(pair(word(), tag(":\n1"))),
+        ));
+        assert_eq!(msg, "4:2: expected \":\\n1\", found \":\"");
+    }
+
+    #[test]
+    fn test_alternatives() {
+        let sign = or(value(tag("+"), 1), value(tag("-"), -1));
+        let op = one_of([("<=", 0), ("<", 1), (">", 2)]);
+        assert_eq!(parse_all("<", &op), Ok(1));
+        assert_eq!(
+            message(parse_all("=", &op)),
+            "1:1: expected one of [\"<=\", \"<\", \">\"], found \"=\""
+        );
+        assert_eq!(parse_all("1 2", pair(int::<i32>(), rest())), Ok((1, " 2")));
+        assert_eq!(parse_all("-", &sign), Ok(-1));
+        assert_eq!(
+            message(parse_all("*", &sign)),
+            "1:1: expected \"-\", found \"*\""
+        );
+        let signed = pair(opt(sign), int::<i32>());
+        assert_eq!(parse_all("5", &signed), Ok((None, 5)));
+        let even = try_map(int::<i32>(), |n| match n % 2 {
+            0 => Ok(n),
+            _ => Err(format!("an even number, not {n}")),
+        });
+        assert_eq!(
+            message(parse_all("x: 3", key_value("x", even))),
+            "1:4: expected an even number, not 3, found \"3\""
+        );
+    }
+
+    #[test]
+    fn test_spans() {
+        let content = "ab\ncd";
+        let names = parse_all(content, lines(spanned(word()))).unwrap();
+        assert_eq!(names[1].value, "cd");
+        assert_eq!(names[1].span, Span { start: 3, end: 5 });
+        let err = error_at(content, names[1].span.start, "unknown name");
+        assert_eq!(err, Error::parse("2:1: unknown name"));
+    }
+}
@@ -0,0 +1,195 @@
+use crate::{
+    geometry::{Dir, Pos},
+    number_theory::Divisors,
+    Error,
+};
+
+/// A lattice point as `(y, x)`, wide enough that long dig plans cannot overflow.
+pub type Point = (i128, i128);
+
+/// Which way the vertices go round, as drawn on screen with `y` growing downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Clockwise,
+    CounterClockwise,
+}
+
+/// A simple polygon on the lattice, the last vertex connects back to the first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<Point>,
+}
+impl Polygon {
+    pub fn new(vertices: Vec<Point>) -> Polygon {
+        return Polygon { vertices };
+    }
+
+    pub fn from_positions(positions: impl IntoIterator<Item = Pos>) -> Polygon {
+        let vertices = positions
+            .into_iter()
+            .map(|pos| (pos.y as i128, pos.x as i128))
+            .collect();
+        return Polygon::new(vertices);
+    }
+
+    /// Polygon traced by walking `length` in `dir` for every step, starting at the origin.
+    /// The walk has to end where it started.
+    pub fn from_steps(steps: impl IntoIterator<Item = (Dir, i64)>) -> Result<Polygon, Error> {
+        let mut vertices = vec![(0, 0)];
+        for (dir, length) in steps {
+            let (dy, dx) = dir.dydx();
+            let &(y, x) = vertices.last().unwrap();
+            let length = length as i128;
+            vertices.push((y + dy as i128 * length, x + dx as i128 * length));
+        }
+        if vertices.pop() != Some((0, 0)) {
+            return Err(Error::parse("the steps do not return to the start"));
+        }
+        return Ok(Polygon::new(vertices));
+    }
+
+    pub fn vertices(&self) -> &[Point] {
+        return &self.vertices;
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (Point, Point)> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        return self.vertices.iter().copied().zip(next.copied());
+    }
+
+    /// Twice the signed area, positive when clockwise on screen.
+    pub fn signed_double_area(&self) -> i128 {
+        // https://en.wikipedia.org/wiki/Shoelace_formula
+        return self
+            .edges()
+            .map(|((y0, x0), (y1, x1))| x0 * y1 - x1 * y0)
+            .sum();
+    }
+
+    /// Area enclosed by the edges, halves rounded down.
+    pub fn area(&self) -> i128 {
+        return self.signed_double_area().abs() / 2;
+    }
+
+    /// `None` when the polygon encloses nothing.
+    pub fn orientation(&self) -> Option<Orientation> {
+        return match self.signed_double_area() {
+            0 => None,
+            a if a > 0 => Some(Orientation::Clockwise),
+            _ => Some(Orientation::CounterClockwise),
+        };
+    }
+
+    /// Lattice points on the edges.
+    pub fn boundary_points(&self) -> i128 {
+        return self
+            .edges()
+            .map(|((y0, x0), (y1, x1))| (y1 - y0).checked_gcd(x1 - x0).unwrap())
+            .sum();
+    }
+
+    /// Lattice points strictly inside, by Pick's theorem `A = I + B/2 - 1`.
+    pub fn interior_points(&self) -> i128 {
+        // https://en.wikipedia.org/wiki/Pick%27s_theorem, doubled so that A stays exact
+        return (self.signed_double_area().abs() - self.boundary_points() + 2) / 2;
+    }
+
+    pub fn on_boundary(&self, pos: Pos) -> bool {
+        let (py, px) = (pos.y as i128, pos.x as i128);
+        return self.edges().any(|((y0, x0), (y1, x1))| {
+            let collinear = (y1 - y0) * (px - x0) == (x1 - x0) * (py - y0);
+            let between =
+                y0.min(y1) <= py && py <= y0.max(y1) && x0.min(x1) <= px && px <= x0.max(x1);
+            collinear && between
+        });
+    }
+
+    /// Whether `pos` is strictly inside, points on the boundary are not.
+    pub fn contains(&self, pos: Pos) -> bool {
+        if self.on_boundary(pos) {
+            return false;
+        }
+        // cast a ray towards +x and count the edges it crosses
+        // https://en.wikipedia.org/wiki/Point_in_polygon
+        let (py, px) = (pos.y as i128, pos.x as i128);
+        let mut inside = false;
+        for ((y0, x0), (y1, x1)) in self.edges() {
+            if (y0 > py) == (y1 > py) {
+                continue;
+            }
+            // the edge crosses row py at x0 + (py - y0) * (x1 - x0) / (y1 - y0)
+            let lhs = (px - x0) * (y1 - y0);
+            let rhs = (py - y0) * (x1 - x0);
+            if (y1 > y0 && lhs < rhs) || (y1 < y0 && lhs > rhs) {
+                inside = !inside;
+            }
+        }
+        return inside;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square() -> Polygon {
+        // 3 by 3 square, drawn clockwise on screen
+        return Polygon::from_steps([
+            (Dir::Right, 2),
+            (Dir::Down, 2),
+            (Dir::Left, 2),
+            (Dir::Up, 2),
+        ])
+        .unwrap();
+    }
+
+    #[test]
+    fn test_measures() {
+        let square = square();
+        assert_eq!(square.vertices(), [(0, 0), (0, 2), (2, 2), (2, 0)]);
+        assert_eq!(square.area(), 4);
+        assert_eq!(square.boundary_points(), 8);
+        assert_eq!(square.interior_points(), 1);
+        assert_eq!(square.orientation(), Some(Orientation::Clockwise));
+        let reversed = Polygon::new(square.vertices().iter().rev().copied().collect());
+        assert_eq!(reversed.orientation(), Some(Orientation::CounterClockwise));
+        assert_eq!(reversed.signed_double_area(), -8);
+    }
+
+    #[test]
+    fn test_diagonal() {
+        // triangle with a slanted edge through (1, 1) and (2, 2)
+        let triangle = Polygon::new(vec![(0, 0), (0, 3), (3, 3)]);
+        assert_eq!(triangle.boundary_points(), 9);
+        assert_eq!(triangle.interior_points(), 1);
+        assert!(triangle.on_boundary(Pos::new(2, 2)));
+        assert!(triangle.contains(Pos::new(1, 2)));
+        assert!(!triangle.contains(Pos::new(2, 1)));
+    }
+
+    #[test]
+    fn test_contains() {
+        let square = square();
+        assert!(square.contains(Pos::new(1, 1)));
+        for pos in [Pos::new(0, 0), Pos::new(1, 2), Pos::new(2, 1)] {
+            assert!(square.on_boundary(pos));
+            assert!(!square.contains(pos));
+        }
+        assert!(!square.contains(Pos::new(1, 3)));
+        assert!(!square.contains(Pos::new(-1, 1)));
+    }
+
+    #[test]
+    fn test_no_overflow() {
+        let big = 1 << 62;
+        let square = Polygon::from_steps([
+            (Dir::Right, big),
+            (Dir::Down, big),
+            (Dir::Left, big),
+            (Dir::Up, big),
+        ])
+        .unwrap();
+        assert_eq!(square.area(), (big as i128) * (big as i128));
+        assert!(Polygon::from_steps([(Dir::Right, 1)]).is_err());
+    }
+}
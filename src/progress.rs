@@ -0,0 +1,71 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use crate::Error;
+
+type Callback = Arc<dyn Fn(u64) + Send + Sync>;
+
+/// Cancellation token and progress callback for solvers that can run for minutes.
+/// Solvers marked with `progress` get one and call [`Progress::tick`] now and then.
+/// Clones share the token, so the runner can cancel while the solver ticks.
+#[derive(Clone, Default)]
+pub struct Progress {
+    cancelled: Arc<AtomicBool>,
+    callback: Option<Callback>,
+}
+impl Progress {
+    pub fn new() -> Self {
+        return Progress::default();
+    }
+
+    /// `callback` gets the amount of work done at every tick.
+    pub fn with_callback(callback: impl Fn(u64) + Send + Sync + 'static) -> Self {
+        return Progress {
+            cancelled: Arc::default(),
+            callback: Some(Arc::new(callback)),
+        };
+    }
+
+    /// Ask the solver to stop at its next tick.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        return self.cancelled.load(Ordering::Relaxed);
+    }
+
+    /// Report `done` units of work, e.g. button presses or visited nodes.
+    /// Fails with [`Error::Cancelled`] once cancelled, for the solver to `?` out of its loop.
+    pub fn tick(&self, done: u64) -> Result<(), Error> {
+        if self.is_cancelled() {
+            return Err(Error::Cancelled);
+        }
+        if let Some(callback) = &self.callback {
+            callback(done);
+        }
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicU64;
+
+    #[test]
+    fn test_tick() {
+        let done = Arc::new(AtomicU64::new(0));
+        let seen = done.clone();
+        let progress = Progress::with_callback(move |n| seen.store(n, Ordering::Relaxed));
+        assert_eq!(progress.tick(7), Ok(()));
+        assert_eq!(done.load(Ordering::Relaxed), 7);
+
+        progress.clone().cancel();
+        assert!(progress.is_cancelled());
+        assert_eq!(progress.tick(8), Err(Error::Cancelled));
+        assert_eq!(done.load(Ordering::Relaxed), 7);
+    }
+}
@@ -0,0 +1,500 @@
+use std::{any::Any, env, ffi::OsString, panic, path::PathBuf};
+
+use crate::{
+    cache,
+    certificate::Certificate,
+    explain::Facts,
+    params::{Param, Params},
+    Answer, Error, Progress,
+};
+
+/// Advent of Code runs for 25 days, two parts each.
+pub const DAYS: u32 = 25;
+pub const PARTS: u32 = 2;
+
+/// Name of the algorithm a solver runs unless another one is selected.
+pub const DEFAULT_ALGO: &str = "default";
+
+/// Signature of every solver once the registry adapted it, see [`Solver::run`].
+pub type Run = fn(&str, &Params, &mut Facts, &Progress) -> Result<Answer, Error>;
+
+/// One solved day/part, callable with the raw puzzle input.
+#[derive(Clone, Copy)]
+pub struct Solver {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub run: Run,
+    /// Puzzle constants the solver reads from its [`Params`].
+    pub params: &'static [Param],
+    /// Whether the solver records [`Facts`] for explain mode.
+    pub explains: bool,
+    /// Whether the solver ticks its [`Progress`] and stops when it is cancelled.
+    pub cancellable: bool,
+    /// Certificates for the answers, for the solvers that can back them up.
+    pub certifier: Option<Certifier>,
+    /// Other algorithms for the same answer, `run` is the [`DEFAULT_ALGO`].
+    pub algos: &'static [Algo],
+    /// Statistics of the input, usually on part 1 as both parts share the parser.
+    pub inspect: Option<Inspect>,
+    /// The parser of the module alone, to time parsing apart from solving.
+    pub validate: Option<Validate>,
+    /// Source of the solver module, to find out what it tests without running anything.
+    pub source: &'static str,
+}
+impl Solver {
+    /// Run the solver with default params, turning a panic into [`Error::Panicked`].
+    pub fn solve(&self, input: &str) -> Result<Answer, Error> {
+        return self.solve_with(input, &Params::new());
+    }
+
+    /// Run the solver with some of its params overridden.
+    pub fn solve_with(&self, input: &str, overrides: &Params) -> Result<Answer, Error> {
+        return self.explain(input, overrides).map(|(answer, _)| answer);
+    }
+
+    /// Run the solver and keep the intermediate results it records.
+    pub fn explain(&self, input: &str, overrides: &Params) -> Result<(Answer, Facts), Error> {
+        return self.explain_with(input, overrides, &Progress::new());
+    }
+
+    /// Like [`Solver::explain`], reporting to `progress` and stopping when it is cancelled.
+    pub fn explain_with(
+        &self,
+        input: &str,
+        overrides: &Params,
+        progress: &Progress,
+    ) -> Result<(Answer, Facts), Error> {
+        let params = Params::resolve(self.params, overrides)?;
+        let mut facts = Facts::new();
+        let answer = catch_panic(|| (self.run)(input, &params, &mut facts, progress))?;
+        return Ok((answer, facts));
+    }
+
+    /// The same solver running the algorithm called `name` instead.
+    pub fn with_algo(&self, name: &str) -> Result<Solver, Error> {
+        if name == DEFAULT_ALGO {
+            return Ok(*self);
+        }
+        let Some(algo) = self.algos.iter().find(|algo| algo.name == name) else {
+            let names = self.algo_names().join(", ");
+            let msg = format!("unknown algorithm {name:?}, try one of: {names}");
+            return Err(Error::Param(msg));
+        };
+        return Ok(Solver {
+            run: algo.run,
+            ..*self
+        });
+    }
+
+    /// The default algorithm first, then the alternatives.
+    pub fn algo_names(&self) -> Vec<&'static str> {
+        let alternatives = self.algos.iter().map(|algo| algo.name);
+        return [DEFAULT_ALGO].into_iter().chain(alternatives).collect();
+    }
+
+    /// Parse `input` without solving, `None` if the module does not register its parser.
+    pub fn validate(&self, input: &str) -> Option<Result<(), Error>> {
+        let validate = self.validate?;
+        return Some(catch_panic(|| validate(input)));
+    }
+
+    /// Changes whenever the solver module's source does, so cached answers go stale.
+    /// Edits to shared modules do not count, `aoc23 run --fresh` recomputes anyway.
+    pub fn version(&self) -> u64 {
+        return cache::fnv1a(self.source.as_bytes());
+    }
+
+    /// Still the template of `aoc23 new`, which answers with [`Error::NoSolver`].
+    pub fn is_stub(&self) -> bool {
+        return self.source.contains("Err(Error::NoSolver {");
+    }
+
+    /// Number of `#[test]` functions in the module that are not `#[ignore]`d, on the examples,
+    /// bad inputs or anything else.
+    pub fn unit_tests(&self) -> usize {
+        return count_unit_tests(self.source);
+    }
+}
+
+fn count_unit_tests(source: &str) -> usize {
+    return source
+        .split("#[test]")
+        .skip(1)
+        .filter(|after| !after.trim_start().starts_with("#[ignore"))
+        .count();
+}
+
+/// Another way to the same answer, e.g. a brute force next to a dynamic programming solver.
+/// Modules list theirs in `pub const ALGOS`, `run` takes the same arguments as [`Solver::run`].
+pub struct Algo {
+    pub name: &'static str,
+    pub help: &'static str,
+    pub run: Run,
+}
+
+/// Facts about the structure of an input, e.g. grid size or node counts, from the parser
+/// of the module without solving anything.
+pub type Inspect = fn(&str) -> Result<Facts, Error>;
+
+/// Parses an input and drops the result, the `validate` of the fuzz targets.
+pub type Validate = fn(&str) -> Result<(), Error>;
+
+/// Solves with evidence for the answer and checks that evidence without the solver.
+#[derive(Clone, Copy)]
+pub struct Certifier {
+    pub certify: fn(&str) -> Result<(Answer, Certificate), Error>,
+    pub check: fn(&str, &Answer, &Certificate) -> Result<(), Error>,
+}
+impl Certifier {
+    /// Solve with default params and return the answer with its certificate.
+    pub fn certify(&self, input: &str) -> Result<(Answer, Certificate), Error> {
+        return catch_panic(|| (self.certify)(input));
+    }
+
+    /// Check `certificate` against the input, [`Error::Rejected`] if it does not back `answer`.
+    pub fn check(
+        &self,
+        input: &str,
+        answer: &Answer,
+        certificate: &Certificate,
+    ) -> Result<(), Error> {
+        return catch_panic(|| (self.check)(input, answer, certificate));
+    }
+}
+
+/// Turns a panic in `f` into [`Error::Panicked`].
+fn catch_panic<T>(f: impl FnOnce() -> Result<T, Error>) -> Result<T, Error> {
+    match panic::catch_unwind(panic::AssertUnwindSafe(f)) {
+        Ok(result) => result,
+        Err(payload) => Err(Error::Panicked(panic_message(payload))),
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        return s.to_string();
+    }
+    if let Some(s) = payload.downcast_ref::<String>() {
+        return s.clone();
+    }
+    return "unknown panic".to_string();
+}
+
+// `run` takes the input, then params, facts or progress if the solver is marked with them.
+// `$year` is the module of the event, it declares its `YEAR`.
+// After a `;`, `certify` adds the `certify` and `check` of the module, `algos` its `ALGOS`,
+// `inspect` its `inspect` and `validate` its `validate`.
+macro_rules! solver {
+    ($year:ident, $day:literal, $part:literal, $module:ident $(, $marker:ident)*;
+        certify $(, $extra:ident)*) => {
+        Solver {
+            certifier: Some(Certifier {
+                certify: crate::$year::$module::certify,
+                check: crate::$year::$module::check,
+            }),
+            ..solver!($year, $day, $part, $module $(, $marker)*; $($extra),*)
+        }
+    };
+    ($year:ident, $day:literal, $part:literal, $module:ident $(, $marker:ident)*;
+        algos $(, $extra:ident)*) => {
+        Solver {
+            algos: crate::$year::$module::ALGOS,
+            ..solver!($year, $day, $part, $module $(, $marker)*; $($extra),*)
+        }
+    };
+    ($year:ident, $day:literal, $part:literal, $module:ident $(, $marker:ident)*;
+        inspect $(, $extra:ident)*) => {
+        Solver {
+            inspect: Some(crate::$year::$module::inspect),
+            ..solver!($year, $day, $part, $module $(, $marker)*; $($extra),*)
+        }
+    };
+    ($year:ident, $day:literal, $part:literal, $module:ident $(, $marker:ident)*;
+        validate $(, $extra:ident)*) => {
+        Solver {
+            validate: Some(crate::$year::$module::validate),
+            ..solver!($year, $day, $part, $module $(, $marker)*; $($extra),*)
+        }
+    };
+    ($year:ident, $day:literal, $part:literal, $module:ident $(, $marker:ident)*;) => {
+        solver!($year, $day, $part, $module $(, $marker)*)
+    };
+    ($year:ident, $day:literal, $part:literal, $module:ident) => {
+        solver!(@ $year, $day, $part, $module,
+            |input, _, _, _| crate::$year::$module::run(input), &[], false, false)
+    };
+    ($year:ident, $day:literal, $part:literal, $module:ident, params) => {
+        solver!(@ $year, $day, $part, $module,
+            |input, params, _, _| crate::$year::$module::run(input, params),
+            crate::$year::$module::PARAMS, false, false)
+    };
+    ($year:ident, $day:literal, $part:literal, $module:ident, explain) => {
+        solver!(@ $year, $day, $part, $module,
+            |input, _, facts, _| crate::$year::$module::run(input, facts), &[], true, false)
+    };
+    ($year:ident, $day:literal, $part:literal, $module:ident, params, explain) => {
+        solver!(@ $year, $day, $part, $module,
+            |input, params, facts, _| crate::$year::$module::run(input, params, facts),
+            crate::$year::$module::PARAMS, true, false)
+    };
+    ($year:ident, $day:literal, $part:literal, $module:ident, progress) => {
+        solver!(@ $year, $day, $part, $module,
+            |input, _, _, progress| crate::$year::$module::run(input, progress), &[], false, true)
+    };
+    (@ $year:ident, $day:literal, $part:literal, $module:ident, $run:expr, $params:expr,
+        $explains:literal, $cancellable:literal) => {
+        Solver {
+            year: crate::$year::YEAR,
+            day: $day,
+            part: $part,
+            run: $run,
+            params: $params,
+            explains: $explains,
+            cancellable: $cancellable,
+            certifier: None,
+            algos: &[],
+            inspect: None,
+            validate: None,
+            source: include_str!(concat!(stringify!($year), "/", stringify!($module), ".rs")),
+        }
+    };
+}
+
+pub const SOLVERS: &[Solver] = &[
+    solver!(y2023, 1, 1, day01_1),
+    solver!(y2023, 1, 2, day01_2),
+    solver!(y2023, 2, 1, day02_1, params),
+    solver!(y2023, 2, 2, day02_2),
+    solver!(y2023, 3, 1, day03_1),
+    solver!(y2023, 3, 2, day03_2),
+    solver!(y2023, 4, 1, day04_1),
+    solver!(y2023, 4, 2, day04_2),
+    solver!(y2023, 5, 1, day05_1; validate),
+    solver!(y2023, 5, 2, day05_2, explain; certify, validate),
+    solver!(y2023, 6, 1, day06_1),
+    solver!(y2023, 6, 2, day06_2),
+    solver!(y2023, 7, 1, day07_1; validate),
+    solver!(y2023, 7, 2, day07_2; validate),
+    solver!(y2023, 8, 1, day08_1; validate),
+    solver!(y2023, 8, 2, day08_2; validate),
+    solver!(y2023, 9, 1, day09_1),
+    solver!(y2023, 9, 2, day09_2),
+    solver!(y2023, 10, 1, day10_1; inspect, validate),
+    solver!(y2023, 10, 2, day10_2; validate),
+    solver!(y2023, 11, 1, day11_1; validate),
+    solver!(y2023, 11, 2, day11_2, params; validate),
+    solver!(y2023, 12, 1, day12_1; algos),
+    solver!(y2023, 12, 2, day12_2, params; algos),
+    solver!(y2023, 13, 1, day13_1; certify),
+    solver!(y2023, 13, 2, day13_2; certify),
+    solver!(y2023, 14, 1, day14_1; inspect, validate),
+    solver!(y2023, 14, 2, day14_2, params, explain; validate),
+    solver!(y2023, 15, 1, day15_1),
+    solver!(y2023, 15, 2, day15_2, params),
+    solver!(y2023, 16, 1, day16_1; inspect, validate),
+    solver!(y2023, 16, 2, day16_2; validate),
+    solver!(y2023, 17, 1, day17_1; certify, inspect, validate),
+    solver!(y2023, 17, 2, day17_2; certify, validate),
+    solver!(y2023, 18, 1, day18_1, explain; validate),
+    solver!(y2023, 18, 2, day18_2, explain; validate),
+    solver!(y2023, 19, 1, day19_1; inspect, validate),
+    solver!(y2023, 19, 2, day19_2; validate),
+    solver!(y2023, 20, 1, day20_1, params; validate),
+    solver!(y2023, 20, 2, day20_2, progress; inspect, validate),
+    solver!(y2023, 21, 1, day21_1, params; inspect, validate),
+    solver!(y2023, 22, 1, day22_1; inspect),
+    solver!(y2023, 22, 2, day22_2; algos),
+    solver!(y2023, 23, 1, day23_1; certify, validate),
+    solver!(y2023, 23, 2, day23_2, progress; certify, algos, inspect, validate),
+];
+
+pub fn find(year: u32, day: u32, part: u32) -> Option<&'static Solver> {
+    return SOLVERS
+        .iter()
+        .find(|s| (s.year, s.day, s.part) == (year, day, part));
+}
+
+pub fn parts_of(year: u32, day: u32) -> Vec<&'static Solver> {
+    return SOLVERS
+        .iter()
+        .filter(|s| (s.year, s.day) == (year, day))
+        .collect();
+}
+
+/// Inspect `input` with whichever part of `day` registers `inspect`, `None` if none does.
+pub fn inspect(year: u32, day: u32, input: &str) -> Option<Result<Facts, Error>> {
+    let inspect = parts_of(year, day).into_iter().find_map(|s| s.inspect)?;
+    return Some(catch_panic(|| inspect(input)));
+}
+
+/// Every event with at least one solver, oldest first.
+pub fn years() -> Vec<u32> {
+    let mut years = SOLVERS.iter().map(|s| s.year).collect::<Vec<_>>();
+    years.sort();
+    years.dedup();
+    return years;
+}
+
+/// Environment variable with the directory of the puzzle inputs, `inputs` when unset.
+pub const INPUTS_ENV: &str = "AOC23_INPUTS";
+
+pub fn inputs_dir() -> PathBuf {
+    return inputs_dir_from(env::var_os(INPUTS_ENV));
+}
+
+/// [`inputs_dir`] for that value of [`INPUTS_ENV`], unset and empty are the same.
+fn inputs_dir_from(value: Option<OsString>) -> PathBuf {
+    return match value {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from("inputs"),
+    };
+}
+
+/// `inputs/<year>/dayNN.txt`, see [`inputs_dir`]. For 2023 the `inputs/dayNN.txt` from before
+/// there were several years still counts when the new one is missing.
+pub fn input_path(year: u32, day: u32) -> PathBuf {
+    let dir = inputs_dir();
+    let path = dir.join(year.to_string()).join(format!("day{day:02}.txt"));
+    let legacy = dir.join(format!("day{day:02}.txt"));
+    if year == 2023 && !path.exists() && legacy.exists() {
+        return legacy;
+    }
+    return path;
+}
+
+/// Solve `input` with every algorithm of the solver and assert they agree, for the tests of
+/// the modules. Returns the answer.
+#[cfg(test)]
+pub(crate) fn assert_algos_agree(
+    year: u32,
+    day: u32,
+    part: u32,
+    input: &str,
+    overrides: &Params,
+) -> Answer {
+    let solver = find(year, day, part).unwrap();
+    assert!(
+        !solver.algos.is_empty(),
+        "{year} day {day} part {part} has no algorithms"
+    );
+    let expected = solver.solve_with(input, overrides).unwrap();
+    for algo in solver.algos {
+        let answer = solver
+            .with_algo(algo.name)
+            .unwrap()
+            .solve_with(input, overrides);
+        assert_eq!(answer.as_ref(), Ok(&expected), "{}", algo.name);
+    }
+    return expected;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unique() {
+        for (i, a) in SOLVERS.iter().enumerate() {
+            for b in &SOLVERS[i + 1..] {
+                assert!((a.year, a.day, a.part) != (b.year, b.day, b.part));
+            }
+        }
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(2023, 17, 2).unwrap().part, 2);
+        assert!(find(2023, 25, 1).is_none());
+        assert!(find(2022, 17, 2).is_none());
+        assert_eq!(parts_of(2023, 21).len(), 1);
+        assert!(years().contains(&2023));
+        assert!(years().windows(2).all(|w| w[0] < w[1]));
+        let expected = inputs_dir().join("2024").join("day07.txt");
+        assert_eq!(input_path(2024, 7), expected);
+    }
+
+    #[test]
+    fn test_inputs_dir() {
+        assert_eq!(inputs_dir_from(None), PathBuf::from("inputs"));
+        assert_eq!(
+            inputs_dir_from(Some(OsString::new())),
+            PathBuf::from("inputs")
+        );
+        let custom = Some(OsString::from("/tmp/aoc"));
+        assert_eq!(inputs_dir_from(custom), PathBuf::from("/tmp/aoc"));
+    }
+
+    #[test]
+    fn test_solve() {
+        let solver = find(2023, 1, 1).unwrap();
+        assert_eq!(solver.solve("1abc2"), Ok(Answer::Int(12)));
+        assert!(matches!(
+            find(2023, 9, 1).unwrap().solve("garbage"),
+            Err(Error::Panicked(_))
+        ));
+    }
+
+    #[test]
+    fn test_certify() {
+        assert!(find(2023, 13, 1).unwrap().certifier.is_some());
+        assert!(find(2023, 23, 2).unwrap().cancellable);
+        assert!(find(2023, 1, 1).unwrap().certifier.is_none());
+
+        let certifier = find(2023, 13, 1).unwrap().certifier.as_ref().unwrap();
+        let (answer, certificate) = certifier.certify("##\n..").unwrap();
+        assert_eq!(answer, Answer::Int(1));
+        assert_eq!(certifier.check("##\n..", &answer, &certificate), Ok(()));
+        assert!(certifier.check("#.\n..", &answer, &certificate).is_err());
+    }
+
+    #[test]
+    fn test_validate() {
+        let solver = find(2023, 14, 1).unwrap();
+        assert_eq!(solver.validate("O.#\n..O"), Some(Ok(())));
+        assert!(solver.validate("O.#\n.O").unwrap().is_err());
+        assert_eq!(find(2023, 1, 1).unwrap().validate("1abc2"), None);
+    }
+
+    #[test]
+    fn test_inspect() {
+        let facts = inspect(2023, 22, "1,0,1~1,2,1\n0,0,5~2,0,5")
+            .unwrap()
+            .unwrap();
+        assert_eq!(facts.get("bricks"), Some("2"));
+        assert_eq!(facts.get("highest brick"), Some("5"));
+        assert_eq!(facts.get("settled height"), Some("2"));
+        assert!(inspect(2023, 10, "no pipes").unwrap().is_err());
+        assert!(inspect(2023, 1, "1abc2").is_none());
+    }
+
+    #[test]
+    fn test_with_algo() {
+        let solver = find(2023, 22, 2).unwrap();
+        assert_eq!(solver.algo_names(), [DEFAULT_ALGO, "single-core"]);
+        let single_core = solver.with_algo("single-core").unwrap();
+        assert!(std::ptr::fn_addr_eq(single_core.run, solver.algos[0].run));
+        assert_eq!(
+            solver.with_algo("quantum").err(),
+            Some(Error::Param(String::from(
+                "unknown algorithm \"quantum\", try one of: default, single-core"
+            )))
+        );
+        for solver in SOLVERS {
+            let names = solver.algo_names();
+            for (i, name) in names.iter().enumerate() {
+                assert!(!names[i + 1..].contains(name), "{name}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_unit_tests() {
+        assert_eq!(find(2023, 1, 1).unwrap().unit_tests(), 1);
+        assert_eq!(find(2023, 2, 1).unwrap().unit_tests(), 2);
+        assert!(!find(2023, 1, 1).unwrap().is_stub());
+        let stub = "#[test]\n#[ignore = \"fill in the example\"]\nfn test() {}";
+        assert_eq!(count_unit_tests(stub), 0);
+        assert_eq!(find(2023, 10, 1).unwrap().unit_tests(), 6);
+    }
+}
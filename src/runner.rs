@@ -0,0 +1,261 @@
+use std::{
+    sync::{
+        atomic::{AtomicU64, AtomicU8, AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{registry::Solver, Answer, Error, Facts, Params, Progress};
+
+// same as the main thread, the deep searches keep their stacks on the heap
+const STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// How often [`run`] calls its watcher.
+const WATCH_EVERY: Duration = Duration::from_millis(100);
+
+// states of a solver thread, a stray one timed out and is still running
+const RUNNING: u8 = 0;
+const FINISHED: u8 = 1;
+const STRAY: u8 = 2;
+
+static STRAYS: AtomicUsize = AtomicUsize::new(0);
+
+/// How many timed out solvers are still running in the background.
+pub fn strays() -> usize {
+    return STRAYS.load(Ordering::Relaxed);
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Solved {
+        answer: Answer,
+        facts: Facts,
+        elapsed: Duration,
+    },
+    Failed(Error),
+    TimedOut(Duration),
+}
+
+/// How far a running solver got, for the watcher of [`run`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tick {
+    pub elapsed: Duration,
+    pub budget: Duration,
+    /// The last amount of work the solver reported, 0 if it does not report any.
+    pub done: u64,
+}
+impl Tick {
+    /// `[#####     ] 12.5s/60s 1234567`, the bar fills up as the budget runs out.
+    pub fn render(&self, width: usize) -> String {
+        let used = self.elapsed.as_secs_f64() / self.budget.as_secs_f64().max(f64::EPSILON);
+        let filled = ((used.min(1.0) * width as f64) as usize).min(width);
+        let mut out = format!(
+            "[{}{}] {:.1}s/{}s",
+            "#".repeat(filled),
+            " ".repeat(width - filled),
+            self.elapsed.as_secs_f64(),
+            self.budget.as_secs_f64()
+        );
+        if self.done > 0 {
+            out += &format!(" {}", self.done);
+        }
+        return out;
+    }
+}
+
+/// Run the solver on a worker thread and give up waiting after `budget`.
+pub fn run_with_budget(solver: &Solver, input: String, budget: Duration) -> Outcome {
+    return run(solver, input, Params::new(), budget, |_| {});
+}
+
+/// Run the solver with some params overridden on a worker thread, calling `watch` every
+/// [`WATCH_EVERY`] while it runs. Once `budget` is spent the solver is cancelled:
+/// cancellable solvers stop at their next tick, the others cannot be killed and keep
+/// spinning in the background until the process exits.
+pub fn run(
+    solver: &Solver,
+    input: String,
+    overrides: Params,
+    budget: Duration,
+    mut watch: impl FnMut(&Tick),
+) -> Outcome {
+    let done = Arc::new(AtomicU64::new(0));
+    let progress = {
+        let done = done.clone();
+        Progress::with_callback(move |n| done.store(n, Ordering::Relaxed))
+    };
+    let (tx, rx) = mpsc::channel();
+    let (solver, worker) = (*solver, progress.clone());
+    let state = Arc::new(AtomicU8::new(RUNNING));
+    let finished = state.clone();
+    thread::Builder::new()
+        .name(format!("day{:02}_{}", solver.day, solver.part))
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let start = Instant::now();
+            let result = solver.explain_with(&input, &overrides, &worker);
+            let _ = tx.send((result, start.elapsed()));
+            if finished.swap(FINISHED, Ordering::Relaxed) == STRAY {
+                STRAYS.fetch_sub(1, Ordering::Relaxed);
+            }
+        })
+        .expect("failed to spawn solver thread");
+    let start = Instant::now();
+    loop {
+        let left = budget.saturating_sub(start.elapsed());
+        match rx.recv_timeout(left.min(WATCH_EVERY)) {
+            Ok((Ok((answer, facts)), elapsed)) => {
+                return Outcome::Solved {
+                    answer,
+                    facts,
+                    elapsed,
+                }
+            }
+            Ok((Err(e), _)) => return Outcome::Failed(e),
+            Err(RecvTimeoutError::Timeout) if start.elapsed() < budget => watch(&Tick {
+                elapsed: start.elapsed(),
+                budget,
+                done: done.load(Ordering::Relaxed),
+            }),
+            Err(_) => {
+                progress.cancel();
+                // counted first so that a thread finishing right now never takes it below zero
+                STRAYS.fetch_add(1, Ordering::Relaxed);
+                if state
+                    .compare_exchange(RUNNING, STRAY, Ordering::Relaxed, Ordering::Relaxed)
+                    .is_err()
+                {
+                    STRAYS.fetch_sub(1, Ordering::Relaxed);
+                }
+                return Outcome::TimedOut(budget);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicBool;
+
+    type Run = fn(&str, &Params, &mut Facts, &Progress) -> Result<Answer, Error>;
+
+    fn answer_42(_: &str, _: &Params, facts: &mut Facts, _: &Progress) -> Result<Answer, Error> {
+        facts.note("question", "unknown");
+        Ok(Answer::Int(42))
+    }
+
+    fn explode(input: &str, _: &Params, _: &mut Facts, _: &Progress) -> Result<Answer, Error> {
+        panic!("bad input: {input}");
+    }
+
+    fn forever(_: &str, _: &Params, _: &mut Facts, _: &Progress) -> Result<Answer, Error> {
+        loop {
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    static STOPPED: AtomicBool = AtomicBool::new(false);
+
+    fn ticking(_: &str, _: &Params, _: &mut Facts, progress: &Progress) -> Result<Answer, Error> {
+        for n in 1.. {
+            if let Err(e) = progress.tick(n) {
+                STOPPED.store(true, Ordering::Relaxed);
+                return Err(e);
+            }
+            thread::sleep(Duration::from_millis(1));
+        }
+        unreachable!()
+    }
+
+    fn solver(run: Run) -> &'static Solver {
+        Box::leak(Box::new(Solver {
+            year: 2023,
+            day: 0,
+            part: 1,
+            run,
+            params: &[],
+            explains: false,
+            cancellable: false,
+            certifier: None,
+            algos: &[],
+            inspect: None,
+            validate: None,
+            source: "",
+        }))
+    }
+
+    #[test]
+    fn test_solved() {
+        let outcome = run_with_budget(solver(answer_42), String::new(), Duration::from_secs(5));
+        let Outcome::Solved { answer, facts, .. } = outcome else {
+            panic!("{outcome:?}");
+        };
+        assert_eq!(answer, Answer::Int(42));
+        assert_eq!(facts.get("question"), Some("unknown"));
+    }
+
+    #[test]
+    fn test_panicked() {
+        let outcome = run_with_budget(solver(explode), "xyz".into(), Duration::from_secs(5));
+        assert_eq!(
+            outcome,
+            Outcome::Failed(Error::Panicked("bad input: xyz".into()))
+        );
+    }
+
+    #[test]
+    fn test_timed_out() {
+        let budget = Duration::from_millis(50);
+        let outcome = run_with_budget(solver(forever), String::new(), budget);
+        assert_eq!(outcome, Outcome::TimedOut(budget));
+        // it keeps spinning in the background
+        assert!(strays() >= 1);
+    }
+
+    #[test]
+    fn test_cancelled() {
+        let budget = Duration::from_millis(350);
+        let mut ticks = vec![];
+        let outcome = run(
+            solver(ticking),
+            String::new(),
+            Params::new(),
+            budget,
+            |tick| {
+                ticks.push(*tick);
+            },
+        );
+        assert_eq!(outcome, Outcome::TimedOut(budget));
+        assert!(ticks.len() >= 2, "{ticks:?}");
+        assert!(ticks.iter().all(|tick| tick.elapsed < budget));
+        assert!(ticks.last().unwrap().done > 0);
+        // the worker notices at its next tick
+        for _ in 0..100 {
+            if STOPPED.load(Ordering::Relaxed) {
+                return;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        panic!("the solver kept running");
+    }
+
+    #[test]
+    fn test_render() {
+        let tick = Tick {
+            elapsed: Duration::from_millis(2500),
+            budget: Duration::from_secs(10),
+            done: 0,
+        };
+        assert_eq!(tick.render(8), "[##      ] 2.5s/10s");
+        let over = Tick {
+            elapsed: Duration::from_secs(12),
+            done: 1234,
+            ..tick
+        };
+        assert_eq!(over.render(4), "[####] 12.0s/10s 1234");
+    }
+}
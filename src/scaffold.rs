@@ -0,0 +1,264 @@
+//! `aoc23 new`: the modules, example fixture and registrations of a new day.
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::registry::{DAYS, PARTS};
+
+/// Answers with [`Error::NoSolver`](crate::Error::NoSolver) until it is filled in, so runs fail
+/// cleanly and `aoc23 status` does not count the day yet.
+fn module_template(year: u32, day: u32, part: u32) -> String {
+    return format!(
+        r#"use crate::{{
+    parser::{{ints, lines, parse_all}},
+    Answer, Error,
+}};
+
+fn parse(content: &str) -> Result<Vec<Vec<i64>>, Error> {{
+    // lines of numbers, replace with the grammar of the day
+    return parse_all(content, lines(ints::<i64>()));
+}}
+
+fn solve(content: &str) -> Result<i64, Error> {{
+    let _input = parse(content)?;
+    // not solved yet, replace with the solution
+    return Err(Error::NoSolver {{ year: {year}, day: {day}, part: {part} }});
+}}
+
+pub fn run(content: &str) -> Result<Answer, Error> {{
+    let result = solve(content)?;
+    return Ok(result.into());
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    #[test]
+    #[ignore = "fill in the example and its answer"]
+    fn test() {{
+        let content = include_str!("../../fixtures/{year}/day{day:02}.txt");
+        let result = solve(content).unwrap();
+        assert_eq!(result, 0);
+    }}
+}}
+"#
+    );
+}
+
+fn year_template(year: u32) -> String {
+    return format!(
+        "//! Advent of Code {year}, one module per day and part.\n\npub const YEAR: u32 = {year};\n\n"
+    );
+}
+
+/// Insert `line` among the lines that have a `key`, before the first one with a larger key.
+/// Without any keyed lines it goes at the end.
+fn insert_sorted<K: Ord>(content: &str, line: &str, key: impl Fn(&str) -> Option<K>) -> String {
+    let new_key = key(line).expect("the new line must have a key");
+    let mut lines: Vec<&str> = content.lines().collect();
+    let keyed: Vec<(usize, K)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| Some((i, key(l)?)))
+        .collect();
+    let at = match keyed.iter().find(|(_, k)| *k > new_key) {
+        Some((i, _)) => *i,
+        None => keyed.last().map_or(lines.len(), |(i, _)| i + 1),
+    };
+    lines.insert(at, line);
+    return lines.join("\n") + "\n";
+}
+
+/// `pub mod day05_1;` sorts by its name.
+fn module_key(line: &str) -> Option<String> {
+    let name = line.strip_prefix("pub mod ")?.strip_suffix(';')?;
+    return Some(name.to_owned());
+}
+
+/// `    solver!(y2023, 5, 2, day05_2, explain),` sorts by year, day and part.
+fn solver_key(line: &str) -> Option<(String, u32, u32)> {
+    let args = line.trim().strip_prefix("solver!(")?;
+    let mut args = args.split(", ");
+    let year = args.next()?.to_owned();
+    let day = args.next()?.parse().ok()?;
+    let part = args.next()?.parse().ok()?;
+    return Some((year, day, part));
+}
+
+fn update(root: &Path, path: &str, f: impl FnOnce(&str) -> String) -> io::Result<PathBuf> {
+    let content = fs::read_to_string(root.join(path))?;
+    fs::write(root.join(path), f(&content))?;
+    return Ok(PathBuf::from(path));
+}
+
+/// Create the modules of both parts of `day` under `root`, a fixture for the puzzle example,
+/// and register them. Returns the files created or changed, relative to `root`.
+/// Nothing is written if the modules already exist.
+pub fn new_day(root: &Path, year: u32, day: u32) -> io::Result<Vec<PathBuf>> {
+    if !(1..=DAYS).contains(&day) {
+        let msg = format!("day must be 1 to {DAYS}, got {day}");
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, msg));
+    }
+    let year_dir = format!("src/y{year}");
+    let modules: Vec<String> = (1..=PARTS)
+        .map(|part| format!("day{day:02}_{part}"))
+        .collect();
+    for module in &modules {
+        let path = format!("{year_dir}/{module}.rs");
+        if root.join(&path).exists() {
+            let msg = format!("{path} already exists");
+            return Err(io::Error::new(io::ErrorKind::AlreadyExists, msg));
+        }
+    }
+
+    let mut changed = vec![];
+    let mod_rs = format!("{year_dir}/mod.rs");
+    if !root.join(&mod_rs).exists() {
+        fs::create_dir_all(root.join(&year_dir))?;
+        fs::write(root.join(&mod_rs), year_template(year))?;
+        changed.push(PathBuf::from(&mod_rs));
+        let line = format!("pub mod y{year};");
+        changed.push(update(root, "src/lib.rs", |content| {
+            return insert_sorted(content, &line, |l| {
+                module_key(l).filter(|m| m.starts_with('y'))
+            });
+        })?);
+    }
+    for (part, module) in (1..=PARTS).zip(&modules) {
+        let path = format!("{year_dir}/{module}.rs");
+        fs::write(root.join(&path), module_template(year, day, part))?;
+        changed.push(PathBuf::from(path));
+    }
+    let fixture = format!("fixtures/{year}/day{day:02}.txt");
+    if !root.join(&fixture).exists() {
+        fs::create_dir_all(root.join(format!("fixtures/{year}")))?;
+        fs::write(root.join(&fixture), "")?;
+        changed.push(PathBuf::from(fixture));
+    }
+    changed.push(update(root, &mod_rs, |content| {
+        let mut content = content.to_owned();
+        for module in &modules {
+            content = insert_sorted(&content, &format!("pub mod {module};"), module_key);
+        }
+        return content;
+    })?);
+    changed.push(update(root, "src/registry.rs", |content| {
+        let mut content = content.to_owned();
+        for (part, module) in (1..=PARTS).zip(&modules) {
+            let line = format!("    solver!(y{year}, {day}, {part}, {module}),");
+            content = insert_sorted(&content, &line, solver_key);
+        }
+        return content;
+    })?);
+    changed.push(update(root, "answers.txt", |content| {
+        let mut content = content.to_owned();
+        if !content.is_empty() && !content.ends_with('\n') {
+            content.push('\n');
+        }
+        for part in 1..=PARTS {
+            // commented out until the answer is accepted
+            content += &format!("# {year} {day} {part} <answer>\n");
+        }
+        return content;
+    })?);
+    return Ok(changed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str = "mod answer;\npub mod store;\npub mod y2023;\n\npub use answer::Answer;\n";
+    const REGISTRY: &str = "pub const SOLVERS: &[Solver] = &[
+    solver!(y2023, 1, 1, day01_1),
+    solver!(y2023, 5, 2, day05_2, explain),
+    solver!(y2023, 23, 1, day23_1),
+];
+";
+    const MOD_RS: &str = "//! Advent of Code 2023\n\npub const YEAR: u32 = 2023;\n\npub mod day01_1;\npub mod day23_1;\n";
+
+    fn fake_repo(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("aoc23-{name}-{}", std::process::id()));
+        fs::create_dir_all(root.join("src/y2023")).unwrap();
+        fs::write(root.join("src/lib.rs"), LIB).unwrap();
+        fs::write(root.join("src/registry.rs"), REGISTRY).unwrap();
+        fs::write(root.join("src/y2023/mod.rs"), MOD_RS).unwrap();
+        fs::write(root.join("answers.txt"), "# <year> <day> <part> <answer>\n").unwrap();
+        return root;
+    }
+
+    fn read(root: &Path, path: &str) -> String {
+        return fs::read_to_string(root.join(path)).unwrap();
+    }
+
+    #[test]
+    fn test_insert_sorted() {
+        let content = "header\npub mod a;\npub mod c;\nfooter";
+        assert_eq!(
+            insert_sorted(content, "pub mod b;", module_key),
+            "header\npub mod a;\npub mod b;\npub mod c;\nfooter\n"
+        );
+        assert_eq!(
+            insert_sorted(content, "pub mod d;", module_key),
+            "header\npub mod a;\npub mod c;\npub mod d;\nfooter\n"
+        );
+        assert_eq!(
+            insert_sorted("x\n", "pub mod a;", module_key),
+            "x\npub mod a;\n"
+        );
+        // day 5 sorts before day 23 even though "23" < "5"
+        let registry = insert_sorted(REGISTRY, "    solver!(y2023, 5, 1, day05_1),", solver_key);
+        assert!(registry
+            .contains("day01_1),\n    solver!(y2023, 5, 1, day05_1),\n    solver!(y2023, 5, 2"));
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = fake_repo("scaffold");
+        let changed = new_day(&root, 2023, 24).unwrap();
+        let again = new_day(&root, 2023, 24).unwrap_err();
+        let files = (
+            read(&root, "src/y2023/day24_2.rs"),
+            read(&root, "src/y2023/mod.rs"),
+            read(&root, "src/registry.rs"),
+            read(&root, "answers.txt"),
+            root.join("fixtures/2023/day24.txt").exists(),
+        );
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(changed.len(), 6);
+        assert_eq!(again.kind(), io::ErrorKind::AlreadyExists);
+        let (module, mod_rs, registry, answers, fixture) = files;
+        assert!(module.contains("include_str!(\"../../fixtures/2023/day24.txt\")"));
+        assert!(module.contains("Err(Error::NoSolver { year: 2023, day: 24, part: 2 })"));
+        assert!(mod_rs.ends_with("pub mod day23_1;\npub mod day24_1;\npub mod day24_2;\n"));
+        assert!(registry.contains(
+            "day23_1),\n    solver!(y2023, 24, 1, day24_1),\n    solver!(y2023, 24, 2, day24_2),\n];"
+        ));
+        assert!(answers.ends_with("# 2023 24 1 <answer>\n# 2023 24 2 <answer>\n"));
+        assert!(fixture);
+    }
+
+    #[test]
+    fn test_new_year() {
+        let root = fake_repo("scaffold-year");
+        new_day(&root, 2024, 1).unwrap();
+        let bad_day = new_day(&root, 2024, 26).unwrap_err();
+        let files = (
+            read(&root, "src/lib.rs"),
+            read(&root, "src/y2024/mod.rs"),
+            read(&root, "src/registry.rs"),
+        );
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(bad_day.kind(), io::ErrorKind::InvalidInput);
+        let (lib, mod_rs, registry) = files;
+        assert!(lib.contains("pub mod y2023;\npub mod y2024;\n\npub use"));
+        assert!(
+            mod_rs.contains("pub const YEAR: u32 = 2024;\n\npub mod day01_1;\npub mod day01_2;\n")
+        );
+        assert!(registry.ends_with("    solver!(y2024, 1, 2, day01_2),\n];\n"));
+    }
+}
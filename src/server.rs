@@ -0,0 +1,292 @@
+//! A small HTTP API over the solvers, for callers without a Rust toolchain.
+//!
+//! `POST /solve/{year}/{day}/{part}` with the puzzle input as the body answers with JSON:
+//! `{"year":2023,"day":7,"part":1,"answer":6440,"elapsed_ms":1.2}` on success, or
+//! `{"error":"parse","message":"..."}` with a 4xx/5xx status otherwise.
+use std::{
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    thread,
+    time::Duration,
+};
+
+use crate::{
+    registry,
+    runner::{self, Outcome},
+    Answer, Error,
+};
+
+/// Inputs are a few dozen KiB, anything much bigger is a mistake.
+const MAX_BODY: usize = 1024 * 1024;
+
+/// The request line and headers together, curl sends a few hundred bytes.
+const MAX_HEAD: u64 = 8 * 1024;
+
+/// How long a client may keep the connection quiet before its thread gives up on it.
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+impl Response {
+    fn error(status: u16, kind: &str, message: impl AsRef<str>) -> Response {
+        let body = format!(
+            "{{\"error\":{},\"message\":{}}}",
+            json_string(kind),
+            json_string(message.as_ref())
+        );
+        return Response { status, body };
+    }
+
+    fn reason(&self) -> &'static str {
+        return match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            413 => "Payload Too Large",
+            431 => "Request Header Fields Too Large",
+            422 => "Unprocessable Entity",
+            500 => "Internal Server Error",
+            503 => "Service Unavailable",
+            504 => "Gateway Timeout",
+            _ => "",
+        };
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out += "\\\"",
+            '\\' => out += "\\\\",
+            '\n' => out += "\\n",
+            '\r' => out += "\\r",
+            '\t' => out += "\\t",
+            c if (c as u32) < 0x20 => out += &format!("\\u{:04x}", c as u32),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    return out;
+}
+
+fn answer_json(answer: &Answer) -> String {
+    return match answer {
+        Answer::Int(value) => value.to_string(),
+        Answer::Text(text) => json_string(text),
+    };
+}
+
+/// The response to one request, solvers get at most `budget` each.
+pub fn respond(method: &str, path: &str, body: &[u8], budget: Duration) -> Response {
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let ["solve", year, day, part] = segments[..] else {
+        return Response::error(404, "not_found", format!("no route for {path}"));
+    };
+    if method != "POST" {
+        return Response::error(405, "method_not_allowed", "use POST");
+    }
+    let (Ok(year), Ok(day), Ok(part)) = (year.parse(), day.parse(), part.parse()) else {
+        let msg = format!("bad year, day or part in {path}");
+        return Response::error(400, "bad_request", msg);
+    };
+    let Some(solver) = registry::find(year, day, part) else {
+        let e = Error::NoSolver { year, day, part };
+        return Response::error(404, "no_solver", e.to_string());
+    };
+    let Ok(input) = String::from_utf8(body.to_vec()) else {
+        return Response::error(400, "bad_request", "the input is not UTF-8");
+    };
+    return match runner::run_with_budget(solver, input, budget) {
+        Outcome::Solved {
+            answer, elapsed, ..
+        } => {
+            let ms = elapsed.as_secs_f64() * 1000.0;
+            let answer = answer_json(&answer);
+            let body = format!(
+                "{{\"year\":{year},\"day\":{day},\"part\":{part},\"answer\":{answer},\"elapsed_ms\":{ms:.3}}}"
+            );
+            Response { status: 200, body }
+        }
+        Outcome::Failed(Error::Parse(msg)) => Response::error(422, "parse", msg),
+        Outcome::Failed(e @ Error::Panicked(_)) => Response::error(500, "panicked", e.to_string()),
+        Outcome::Failed(e) => Response::error(500, "failed", e.to_string()),
+        Outcome::TimedOut(budget) => Response::error(
+            504,
+            "timeout",
+            format!("no answer within {}s", budget.as_secs_f64()),
+        ),
+    };
+}
+
+#[derive(Debug)]
+struct Request {
+    method: String,
+    path: String,
+    body: Vec<u8>,
+}
+
+/// Read one request, `Err` holds the response for requests that are not valid HTTP.
+fn read_request(stream: &mut impl Read) -> io::Result<Result<Request, Response>> {
+    let too_large = || {
+        Response::error(
+            431,
+            "too_large",
+            format!("the headers are over {MAX_HEAD} bytes"),
+        )
+    };
+    // the head gets MAX_HEAD bytes at most, a line cut short by the limit has no newline
+    let mut head = BufReader::new(stream).take(MAX_HEAD);
+    let mut line = String::new();
+    head.read_line(&mut line)?;
+    if !line.ends_with('\n') && head.limit() == 0 {
+        return Ok(Err(too_large()));
+    }
+    let mut words = line.split_whitespace();
+    let (Some(method), Some(path)) = (words.next(), words.next()) else {
+        return Ok(Err(Response::error(400, "bad_request", "bad request line")));
+    };
+    let (method, path) = (method.to_owned(), path.to_owned());
+    let mut content_length = 0;
+    loop {
+        line.clear();
+        if head.read_line(&mut line)? == 0 || line.trim_end().is_empty() {
+            if !line.ends_with('\n') && head.limit() == 0 {
+                return Ok(Err(too_large()));
+            }
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                let Ok(length) = value.trim().parse() else {
+                    return Ok(Err(Response::error(
+                        400,
+                        "bad_request",
+                        "bad Content-Length",
+                    )));
+                };
+                content_length = length;
+            }
+        }
+    }
+    if content_length > MAX_BODY {
+        let msg = format!("the input is over {MAX_BODY} bytes");
+        return Ok(Err(Response::error(413, "too_large", msg)));
+    }
+    let mut body = vec![0; content_length];
+    head.into_inner().read_exact(&mut body)?;
+    return Ok(Ok(Request { method, path, body }));
+}
+
+/// Timed out solvers that cannot be stopped keep their core until they finish, one per core at most.
+fn max_strays() -> usize {
+    return thread::available_parallelism().map_or(1, |n| n.get());
+}
+
+fn handle(mut stream: TcpStream, budget: Duration) -> io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let response = match read_request(&mut stream)? {
+        Ok(_) if runner::strays() >= max_strays() => {
+            let msg = "every core is busy with a timed out solver, try again later";
+            Response::error(503, "busy", msg)
+        }
+        Ok(request) => respond(&request.method, &request.path, &request.body, budget),
+        Err(response) => response,
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.reason(),
+        response.body.len(),
+        response.body
+    )?;
+    return stream.flush();
+}
+
+/// Answer requests on `listener` forever, one thread per connection.
+pub fn serve(listener: TcpListener, budget: Duration) -> io::Result<()> {
+    for stream in listener.incoming() {
+        let stream = stream?;
+        thread::spawn(move || {
+            // the client hung up or sent garbage, nobody is left to tell
+            let _ = handle(stream, budget);
+        });
+    }
+    return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BUDGET: Duration = Duration::from_secs(5);
+
+    #[test]
+    fn test_solve() {
+        let input = b"0 3 6 9 12 15";
+        let response = respond("POST", "/solve/2023/9/1", input, BUDGET);
+        assert_eq!(response.status, 200);
+        assert!(
+            response
+                .body
+                .starts_with("{\"year\":2023,\"day\":9,\"part\":1,\"answer\":18,\"elapsed_ms\":"),
+            "{}",
+            response.body
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let parse = respond("POST", "/solve/2023/10/1", b"not a pipe maze", BUDGET);
+        assert_eq!(parse.status, 422);
+        assert!(parse.body.starts_with("{\"error\":\"parse\",\"message\":"));
+        let cases = [
+            ("POST", "/solve/2023/25/1", 404, "no_solver"),
+            ("POST", "/solve/2023/x/1", 400, "bad_request"),
+            ("POST", "/solve/9/1", 404, "not_found"),
+            ("GET", "/solve/2023/9/1", 405, "method_not_allowed"),
+            ("POST", "/answers", 404, "not_found"),
+        ];
+        for (method, path, status, kind) in cases {
+            let response = respond(method, path, b"", BUDGET);
+            assert_eq!(response.status, status, "{path}");
+            assert!(
+                response.body.contains(&format!("\"error\":\"{kind}\"")),
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_read_request() {
+        let raw = b"POST /solve/2023/9/1 HTTP/1.1\r\nContent-Length: 4\r\n\r\n1 2 3";
+        let request = read_request(&mut &raw[..]).unwrap().unwrap();
+        assert_eq!(
+            (request.method.as_str(), request.path.as_str()),
+            ("POST", "/solve/2023/9/1")
+        );
+        assert_eq!(request.body, b"1 2 ");
+
+        let long_path = format!("POST /{} HTTP/1.1\r\n\r\n", "a".repeat(MAX_HEAD as usize));
+        let long_header = format!(
+            "POST / HTTP/1.1\r\nX-Junk: {}\r\n\r\n",
+            "a".repeat(MAX_HEAD as usize)
+        );
+        let many_headers = format!("POST / HTTP/1.1\r\n{}\r\n", "X: y\r\n".repeat(2000));
+        for raw in [long_path, long_header, many_headers] {
+            let response = read_request(&mut raw.as_bytes()).unwrap().unwrap_err();
+            assert_eq!(response.status, 431);
+        }
+    }
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("a \"b\"\n\\"), "\"a \\\"b\\\"\\n\\\\\"");
+        assert_eq!(json_string("\u{1}"), "\"\\u0001\"");
+    }
+}
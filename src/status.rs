@@ -0,0 +1,127 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use crate::{
+    registry::{self, DAYS, PARTS},
+    store::Key,
+    Answer,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Status {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub has_solver: bool,
+    pub answer: Option<Answer>,
+    pub unit_tests: usize,
+    pub runtime: Option<Duration>,
+}
+
+/// One row per puzzle part of every year with a solver, solved or not, so the gaps are visible too.
+pub fn collect(answers: &BTreeMap<Key, Answer>, runtimes: &BTreeMap<Key, Duration>) -> Vec<Status> {
+    let mut statuses = vec![];
+    for year in registry::years() {
+        for day in 1..=DAYS {
+            for part in 1..=PARTS {
+                let solver = registry::find(year, day, part);
+                let key = (year, day, part);
+                statuses.push(Status {
+                    year,
+                    day,
+                    part,
+                    // a stub from `aoc23 new` does not count until it is filled in
+                    has_solver: solver.is_some_and(|s| !s.is_stub()),
+                    answer: answers.get(&key).cloned(),
+                    unit_tests: solver.map_or(0, |s| s.unit_tests()),
+                    runtime: runtimes.get(&key).copied(),
+                });
+            }
+        }
+    }
+    return statuses;
+}
+
+pub fn format_duration(d: Duration) -> String {
+    if d < Duration::from_millis(1) {
+        return format!("{}us", d.as_micros());
+    }
+    if d < Duration::from_secs(1) {
+        return format!("{}ms", d.as_millis());
+    }
+    return format!("{:.1}s", d.as_secs_f64());
+}
+
+pub fn render(statuses: &[Status]) -> String {
+    let mut out = format!(
+        "{:>4}  {:>3}  {:>4}  {:<6}  {:>5}  {:<16}  {:>8}\n",
+        "year", "day", "part", "solver", "tests", "verified answer", "runtime"
+    );
+    for s in statuses {
+        let has_solver = if s.has_solver { "x" } else { "" };
+        let answer = s.answer.as_ref().map_or(String::new(), |a| a.to_string());
+        let runtime = s.runtime.map_or(String::new(), format_duration);
+        let line = format!(
+            "{:>4}  {:>3}  {:>4}  {:<6}  {:>5}  {:<16}  {:>8}",
+            s.year, s.day, s.part, has_solver, s.unit_tests, answer, runtime
+        );
+        out += line.trim_end();
+        out += "\n";
+    }
+    let n_solved = statuses.iter().filter(|s| s.has_solver).count();
+    let n_verified = statuses.iter().filter(|s| s.answer.is_some()).count();
+    out += &format!(
+        "\n{n_solved}/{} parts have a solver, {n_verified} with a verified answer\n",
+        statuses.len()
+    );
+    return out;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collect() {
+        let answers = BTreeMap::from([((2023, 1, 1), Answer::Int(54338))]);
+        let runtimes = BTreeMap::from([((2023, 1, 1), Duration::from_micros(700))]);
+        let statuses = collect(&answers, &runtimes);
+        assert_eq!(statuses.len(), registry::years().len() * 50);
+        // new events add their rows, the ones of 2023 stay the same
+        let statuses: Vec<_> = statuses.into_iter().filter(|s| s.year == 2023).collect();
+        assert_eq!(statuses.len(), 50);
+        assert_eq!(
+            statuses[0],
+            Status {
+                year: 2023,
+                day: 1,
+                part: 1,
+                has_solver: true,
+                answer: Some(Answer::Int(54338)),
+                unit_tests: 1,
+                runtime: Some(Duration::from_micros(700)),
+            }
+        );
+        // day 25 has no solver yet
+        assert!(!statuses[49].has_solver);
+        assert_eq!(statuses[49].unit_tests, 0);
+    }
+
+    #[test]
+    fn test_render() {
+        let statuses = collect(
+            &BTreeMap::from([((2023, 1, 1), Answer::Int(54338))]),
+            &BTreeMap::new(),
+        );
+        let statuses: Vec<_> = statuses.into_iter().filter(|s| s.year == 2023).collect();
+        let table = render(&statuses);
+        assert!(table.contains("2023    1     1  x           1  54338"));
+        assert!(table.ends_with("45/50 parts have a solver, 1 with a verified answer\n"));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_micros(42)), "42us");
+        assert_eq!(format_duration(Duration::from_millis(42)), "42ms");
+        assert_eq!(format_duration(Duration::from_millis(4200)), "4.2s");
+    }
+}
@@ -0,0 +1,112 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::Answer;
+
+/// Accepted answers for our own inputs, one `<year> <day> <part> <answer>` per line.
+pub const ANSWERS_PATH: &str = "answers.txt";
+/// Local state that should not be committed, e.g. measured runtimes.
+pub const STATE_DIR: &str = ".aoc23";
+
+pub fn runtimes_path() -> PathBuf {
+    return Path::new(STATE_DIR).join("runtimes.txt");
+}
+
+/// A puzzle part as `(year, day, part)`.
+pub type Key = (u32, u32, u32);
+
+/// Reads `<year> <day> <part> <value>` lines, skipping blanks and `#` comments.
+/// `<day> <part> <value>` lines from before there were several years are 2023.
+fn parse_table(content: &str) -> BTreeMap<Key, String> {
+    let mut table = BTreeMap::new();
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let tokens = line.split_whitespace().collect::<Vec<_>>();
+        let (year, day, part, value) = match tokens[..] {
+            [year, day, part, value] => (year, day, part, value),
+            [day, part, value] => ("2023", day, part, value),
+            _ => continue,
+        };
+        if let (Ok(year), Ok(day), Ok(part)) = (year.parse(), day.parse(), part.parse()) {
+            table.insert((year, day, part), value.to_string());
+        }
+    }
+    return table;
+}
+
+fn load_table(path: &Path) -> io::Result<BTreeMap<Key, String>> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(parse_table(&content)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn load_answers(path: &Path) -> io::Result<BTreeMap<Key, Answer>> {
+    let answers = load_table(path)?
+        .into_iter()
+        .filter_map(|(key, value)| Some((key, value.parse().ok()?)))
+        .collect();
+    return Ok(answers);
+}
+
+pub fn load_runtimes(path: &Path) -> io::Result<BTreeMap<Key, Duration>> {
+    let runtimes = load_table(path)?
+        .into_iter()
+        .filter_map(|(key, value)| Some((key, Duration::from_micros(value.parse().ok()?))))
+        .collect();
+    return Ok(runtimes);
+}
+
+/// Overwrite the last measured runtime of a puzzle part, stored in microseconds.
+pub fn record_runtime(path: &Path, key: Key, elapsed: Duration) -> io::Result<()> {
+    let mut runtimes = load_runtimes(path)?;
+    runtimes.insert(key, elapsed);
+    let mut content = String::new();
+    for ((year, day, part), elapsed) in runtimes {
+        content += &format!("{year} {day} {part} {}\n", elapsed.as_micros());
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    return fs::write(path, content);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_table() {
+        let table = parse_table(
+            "# year day part answer\n2023 1 1 142\n\n  2022 1 2 281 \nbad line\nx 1 2\n",
+        );
+        assert_eq!(table.len(), 2);
+        assert_eq!(table[&(2022, 1, 2)], "281");
+        // the layout from before there were several years
+        assert_eq!(parse_table("1 2 281")[&(2023, 1, 2)], "281");
+    }
+
+    #[test]
+    fn test_runtimes_roundtrip() {
+        let dir = std::env::temp_dir().join(format!("aoc23-store-{}", std::process::id()));
+        let path = dir.join("runtimes.txt");
+        assert!(load_runtimes(&path).unwrap().is_empty());
+        record_runtime(&path, (2023, 17, 2), Duration::from_millis(1500)).unwrap();
+        record_runtime(&path, (2023, 3, 1), Duration::from_micros(42)).unwrap();
+        record_runtime(&path, (2023, 17, 2), Duration::from_millis(900)).unwrap();
+        let runtimes = load_runtimes(&path).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(runtimes.len(), 2);
+        assert_eq!(runtimes[&(2023, 17, 2)], Duration::from_millis(900));
+        assert_eq!(runtimes[&(2023, 3, 1)], Duration::from_micros(42));
+    }
+}
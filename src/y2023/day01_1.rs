@@ -1,8 +1,8 @@
-use std::{fs, path::Path};
+use crate::{Answer, Error};
 
 fn find_first(s: &str) -> i32 {
     for c in s.chars() {
-        if c >= '0' && c <= '9' {
+        if c.is_ascii_digit() {
             return c.to_digit(10).unwrap() as i32;
         }
     }
@@ -11,7 +11,7 @@ fn find_first(s: &str) -> i32 {
 
 fn find_last(s: &str) -> i32 {
     for c in s.chars().rev() {
-        if c >= '0' && c <= '9' {
+        if c.is_ascii_digit() {
             return c.to_digit(10).unwrap() as i32;
         }
     }
@@ -28,14 +28,13 @@ fn calculate_sum_calibrations(lines: Vec<&str>) -> i32 {
     return total;
 }
 
-fn main() {
-    let input_path = Path::new("inputs/day01.txt");
-    let content = fs::read_to_string(input_path).expect("missing file for day 1");
+pub fn run(content: &str) -> Result<Answer, Error> {
     let splitted: Vec<&str> = content.split_whitespace().collect();
     let result = calculate_sum_calibrations(splitted);
-    println!("day 1 part 1: {}", result);
+    return Ok(result.into());
 }
 
+#[cfg(test)]
 mod tests {
     use super::*;
 
@@ -1,4 +1,6 @@
-use std::{collections::HashMap, fs, path::Path};
+use std::collections::HashMap;
+
+use crate::{Answer, Error};
 
 fn find_from_left(line: &str, mapping: &HashMap<String, i32>) -> i32 {
     let mut earliest_index = 9999999;
@@ -21,7 +23,7 @@ fn find_from_right(line: &str, mapping: &HashMap<String, i32>) -> i32 {
     let line: String = line.chars().rev().collect();
     let mapping: HashMap<String, i32> = mapping
         .iter()
-        .map(|(s, i)| (s.chars().rev().collect(), i.clone()))
+        .map(|(s, i)| (s.chars().rev().collect(), *i))
         .collect();
 
     let mut earliest_index = 9999999;
@@ -71,14 +73,13 @@ fn solve(lines: &Vec<&str>) -> i32 {
     return total;
 }
 
-fn main() {
-    let filepath = Path::new("inputs/day01.txt");
-    let content = fs::read_to_string(filepath).expect("day 1 file not found");
+pub fn run(content: &str) -> Result<Answer, Error> {
     let lines = content.split_whitespace().collect();
     let result = solve(&lines);
-    println!("day 1 part 2: {}", result);
+    return Ok(result.into());
 }
 
+#[cfg(test)]
 mod test {
     use super::*;
     #[test]
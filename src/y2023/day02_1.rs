@@ -0,0 +1,98 @@
+use crate::{dump, params::Param, Answer, Error, Params};
+
+fn solve(games: &str, red: i32, green: i32, blue: i32) -> i32 {
+    // parse games:
+    // Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+    // Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+    let mut total = 0;
+    for game in games.lines() {
+        let (game_id, groups) = game.split_once(":").unwrap();
+        let game_id = game_id.split_once(" ").unwrap().1.parse::<i32>().unwrap();
+        let mut under_constraint = true;
+        for group in groups.split(";") {
+            for num_color in group.split(",") {
+                let (num, color) = num_color.trim().split_once(" ").unwrap();
+                let num = num.parse::<i32>().unwrap();
+                let is_possible = match color {
+                    "red" => num <= red,
+                    "green" => num <= green,
+                    "blue" => num <= blue,
+                    _ => {
+                        dump!(Debug, "unknown color |{color}|");
+                        false
+                    }
+                };
+                if !is_possible {
+                    under_constraint = false
+                }
+            }
+        }
+        if under_constraint {
+            total += game_id;
+        }
+    }
+    return total;
+}
+
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "red",
+        default: 12,
+        min: 0,
+        max: i64::MAX,
+        help: "red cubes in the bag",
+    },
+    Param {
+        name: "green",
+        default: 13,
+        min: 0,
+        max: i64::MAX,
+        help: "green cubes in the bag",
+    },
+    Param {
+        name: "blue",
+        default: 14,
+        min: 0,
+        max: i64::MAX,
+        help: "blue cubes in the bag",
+    },
+];
+
+pub fn run(content: &str, params: &Params) -> Result<Answer, Error> {
+    let (red, green, blue) = (
+        params.get("red")?,
+        params.get("green")?,
+        params.get("blue")?,
+    );
+    let result = solve(content, red, green, blue);
+    return Ok(result.into());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    const GAMES: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    #[test]
+    fn test() {
+        let result = solve(GAMES, 12, 13, 14);
+        assert_eq!(result, 8);
+    }
+
+    #[test]
+    fn test_params() {
+        // games 3 and 4 need 20 red and 15 blue cubes
+        let mut overrides = Params::new();
+        overrides.set("red", 20);
+        overrides.set("blue", 15);
+        let solver = registry::find(2023, 2, 1).unwrap();
+        assert_eq!(solver.solve_with(GAMES, &overrides), Ok(Answer::Int(15)));
+        assert_eq!(solver.solve(GAMES), Ok(Answer::Int(8)));
+    }
+}
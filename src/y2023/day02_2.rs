@@ -1,8 +1,10 @@
-use std::{cmp, fs, path::Path};
+use std::cmp;
 
 use regex::Regex;
 
-fn solve(games: String) -> i32 {
+use crate::{dump, Answer, Error};
+
+fn solve(games: &str) -> i32 {
     let mut total = 0;
     let re = Regex::new(r"(\d+) (red|green|blue)").unwrap();
     for game in games.lines() {
@@ -10,7 +12,7 @@ fn solve(games: String) -> i32 {
         let (mut red, mut green, mut blue) = (0, 0, 0);
         for item in re.captures_iter(groups) {
             let (_, [count, color]) = item.extract();
-            // println!("{} {}", count, color);
+            dump!(Trace, "{count} {color}");
             let count = count.parse::<i32>().unwrap();
             match color {
                 "red" => red = cmp::max(red, count),
@@ -19,20 +21,19 @@ fn solve(games: String) -> i32 {
                 _ => {}
             }
         }
-        // println!("{} {} {}", red, green, blue);
+        dump!(Debug, "{red} {green} {blue}");
         total += red * green * blue;
     }
 
     return total;
 }
 
-fn main() {
-    let content = fs::read_to_string(Path::new("inputs/day02.txt"))
-        .expect("input for day 2 is missing");
+pub fn run(content: &str) -> Result<Answer, Error> {
     let result = solve(content);
-    println!("day 2 part 2: {}", result);
+    return Ok(result.into());
 }
 
+#[cfg(test)]
 mod tests {
     use super::*;
     #[test]
@@ -44,7 +45,7 @@ Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
 Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
 Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green",
         );
-        let result = solve(games);
+        let result = solve(&games);
         assert_eq!(result, 2286);
     }
 }
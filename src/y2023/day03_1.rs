@@ -0,0 +1,117 @@
+use std::collections::HashSet;
+
+use crate::{dump, geometry::Pos, Answer, Error, Grid};
+
+fn parse(engine: &str) -> Result<Grid<char>, Error> {
+    return Grid::parse(engine, Some);
+}
+
+fn floodfill(start: Pos, board: &Grid<char>, visited: &mut Grid<bool>) {
+    let dirs = [
+        (-1, -1),
+        (-1, 0),
+        (-1, 1),
+        (0, -1),
+        (0, 1),
+        (1, -1),
+        (1, 0),
+        (1, 1),
+    ];
+    // an explicit stack, a recursion as deep as a big blob of digits overflows
+    let mut stack = vec![start];
+    while let Some(pos) = stack.pop() {
+        let Some(&ch) = board.get(pos) else {
+            continue;
+        };
+        if visited[pos] {
+            continue;
+        }
+        if ch == '.' {
+            continue;
+        }
+        visited[pos] = true;
+        for (dy, dx) in dirs {
+            stack.push(pos + Pos::new(dy, dx));
+        }
+    }
+}
+
+fn sum_connected(board: &Grid<char>, visited: &Grid<bool>) -> i32 {
+    let numbers: HashSet<char> = HashSet::from_iter("0123456789".chars());
+    let mut total = 0;
+    for (row, visited_row) in board.rows().zip(visited.rows()) {
+        let mut curr_group = 0;
+        for (ch, &seen) in row.iter().zip(visited_row) {
+            if !(numbers.contains(ch) && seen) {
+                total += curr_group;
+                if curr_group != 0 {
+                    dump!(Trace, "{curr_group}");
+                }
+                curr_group = 0;
+                continue;
+            }
+            // at this point, the char is connected to the symbol and aslo parsable to int
+            curr_group = 10 * curr_group + ch.to_digit(10).unwrap() as i32;
+        }
+        if curr_group != 0 {
+            dump!(Trace, "{curr_group}");
+        }
+        total += curr_group;
+    }
+    return total;
+}
+
+fn solve(engine: &str) -> Result<i32, Error> {
+    // the idea is to do floodfill and only keeping the numbers that is floodfilled
+    let board = parse(engine)?;
+    let mut visited = Grid::new(board.h(), board.w(), false);
+    let not_symbols: HashSet<char> = HashSet::from_iter("0123456789.".chars());
+    for (pos, ch) in board.iter() {
+        if !not_symbols.contains(ch) {
+            floodfill(pos, &board, &mut visited)
+        }
+    }
+    // now collect all true
+    let total = sum_connected(&board, &visited);
+    dump!(
+        Debug,
+        "connected to a symbol\n{}",
+        visited.render(|&seen| if seen { '#' } else { '.' })
+    );
+    return Ok(total);
+}
+
+pub fn run(content: &str) -> Result<Answer, Error> {
+    let result = solve(content)?;
+    return Ok(result.into());
+}
+
+// cfg test is useful to silence the not used warning
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test() {
+        let engine = String::from(
+            "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..",
+        );
+        let result = solve(&engine).unwrap();
+        assert_eq!(result, 4361);
+    }
+
+    #[test]
+    fn test_deep() {
+        // a column of digits under a symbol, the flood fill goes all the way down
+        let engine = format!("*\n{}", "1\n".repeat(100_000));
+        assert_eq!(solve(&engine).unwrap(), 100_000);
+    }
+}
@@ -0,0 +1,143 @@
+use std::collections::HashSet;
+
+use crate::{dump, geometry::Pos, Answer, Error, Grid};
+
+fn find_gear_locations(engine: &Grid<char>) -> Vec<Pos> {
+    return engine
+        .iter()
+        .filter(|(_, c)| **c == '*')
+        .map(|(pos, _)| pos)
+        .collect();
+}
+
+fn parse_to_number(engine: &Grid<char>) -> Grid<i32> {
+    return engine.map(|x| match x.to_digit(10) {
+        Some(num) => num as i32,
+        None => -1,
+    });
+}
+
+fn groupify(parsed: &Grid<i32>) -> Grid<i32> {
+    let mut groups = Grid::new(parsed.h(), parsed.w(), -1);
+    let mut curr_group = 0;
+    let mut previously_valid = false;
+    for y in 0..parsed.h() as i64 {
+        for x in 0..parsed.w() as i64 {
+            let pos = Pos::new(y, x);
+            if parsed[pos] != -1 {
+                groups[pos] = curr_group;
+                previously_valid = true;
+            } else {
+                if previously_valid {
+                    curr_group += 1;
+                    previously_valid = false;
+                }
+            }
+        }
+        if previously_valid {
+            curr_group += 1;
+            previously_valid = false;
+        }
+    }
+    return groups;
+}
+
+fn create_group_mapping(parsed: &Grid<i32>) -> Vec<i32> {
+    let mut mapping = vec![];
+    let mut curr_num = 0;
+    let mut previously_valid = false;
+    for row in parsed.rows() {
+        for &num in row {
+            if num != -1 {
+                curr_num = 10 * curr_num + num;
+                previously_valid = true;
+            } else {
+                if previously_valid {
+                    mapping.push(curr_num);
+                    curr_num = 0;
+                    previously_valid = false;
+                }
+            }
+        }
+        if previously_valid {
+            mapping.push(curr_num);
+            curr_num = 0;
+            previously_valid = false;
+        }
+    }
+    return mapping;
+}
+
+fn solve(engine: &str) -> Result<i32, Error> {
+    let engine = Grid::parse(engine, Some)?;
+    let gear_locations = find_gear_locations(&engine);
+    let parsed = parse_to_number(&engine);
+    let groups = groupify(&parsed);
+    let group_mapping = create_group_mapping(&parsed);
+
+    dump!(
+        Debug,
+        "digits\n{}",
+        parsed.render(|&num| char::from_digit(num as u32, 10).unwrap_or('.'))
+    );
+    dump!(Debug, "gears {gear_locations:?}");
+    dump!(Debug, "groups {group_mapping:?}");
+
+    // with this information we can compute the solution
+    let mut total = 0;
+    let dirs = [
+        (-1, -1),
+        (-1, 0),
+        (-1, 1),
+        (0, -1),
+        (0, 1),
+        (1, -1),
+        (1, 0),
+        (1, 1),
+    ];
+    for pos in gear_locations {
+        // count unique groups from the 8 directions, and only allow exactly 2
+        let mut set = HashSet::new();
+        for (dy, dx) in dirs {
+            if let Some(&gid) = groups.get(pos + Pos::new(dy, dx)) {
+                set.insert(gid); // add group id on 8 directions
+            }
+        }
+        set.remove(&-1); // remove non real group is (-1)
+        if set.len() != 2 {
+            continue;
+        }
+        total += set
+            .iter()
+            .map(|gid| group_mapping[*gid as usize])
+            .product::<i32>();
+    }
+    return Ok(total);
+}
+
+pub fn run(content: &str) -> Result<Answer, Error> {
+    let result = solve(content)?;
+    return Ok(result.into());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test() {
+        let content = String::from(
+            "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..",
+        );
+        let result = solve(&content).unwrap();
+        assert_eq!(result, 467835);
+    }
+}
@@ -1,16 +1,16 @@
-use std::{collections::HashSet, fs, path::Path};
+use std::collections::HashSet;
 
-fn solve(cards: String) -> i32 {
+use crate::{Answer, Error};
+
+fn solve(cards: &str) -> i32 {
     let mut total = 0;
     for line in cards.lines() {
         let (winning, yours) = line.split_once(":").unwrap().1.split_once("|").unwrap();
         let winning_nums = winning
-            .trim()
             .split_whitespace()
             .map(|num| num.parse::<i32>().unwrap())
             .collect::<HashSet<i32>>();
         let your_nums = yours
-            .trim()
             .split_whitespace()
             .map(|num| num.parse::<i32>().unwrap())
             .collect::<HashSet<i32>>();
@@ -23,11 +23,9 @@ fn solve(cards: String) -> i32 {
     return total;
 }
 
-fn main() {
-    let content = fs::read_to_string(Path::new("inputs/day04.txt"))
-        .expect("input for day 4 is missing");
+pub fn run(content: &str) -> Result<Answer, Error> {
     let result = solve(content);
-    println!("day 4 part 1: {}", result);
+    return Ok(result.into());
 }
 
 #[cfg(test)]
@@ -43,7 +41,7 @@ mod tests {
         Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
         Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11",
         );
-        let result = solve(content);
+        let result = solve(&content);
         assert_eq!(result, 13);
     }
 }
@@ -0,0 +1,151 @@
+use crate::{
+    parser::{
+        ints, key_value, lines, map, pair, parse_all, preceded, sections, tag, take_while1,
+        terminated, try_map,
+    },
+    Answer, Error,
+};
+
+struct Mapper {
+    dest: i64,
+    source: i64,
+    range: i64,
+}
+struct MapperBlock {
+    mappers: Vec<Mapper>,
+}
+
+impl MapperBlock {
+    // notes:
+    // `&self` is just simplification of
+    // `self: &Self` which is also simplification of
+    // `self: &MapperBlock`
+    fn remap(&self, x: i64) -> i64 {
+        for mapper in &self.mappers {
+            // example source 98, dest 50, range 2 is (98, 99) -> (50, 51)
+            if mapper.source <= x && x < mapper.source + mapper.range {
+                let diff = x - mapper.source;
+                return mapper.dest + diff;
+            }
+        }
+        return x; // unmapped, just return original
+    }
+}
+
+struct MapperPipeline {
+    mapper_blocks: Vec<MapperBlock>,
+}
+
+impl MapperPipeline {
+    fn get_last(&self, x: i64) -> i64 {
+        let mut result = x;
+        for block in &self.mapper_blocks {
+            result = block.remap(result);
+        }
+        return result;
+    }
+}
+
+fn parse(content: &str) -> Result<(Vec<i64>, MapperPipeline), Error> {
+    let seeds = key_value("seeds", ints::<i64>());
+    let mapper = try_map(ints::<i64>(), |nums| {
+        let [dest, source, range] = nums[..] else {
+            return Err(format!("3 numbers, not {}", nums.len()));
+        };
+        if source.checked_add(range).is_none() || dest.checked_add(range).is_none() {
+            return Err(format!("a range that fits an i64, not {range}"));
+        }
+        return Ok(Mapper {
+            dest,
+            source,
+            range,
+        });
+    });
+    let header = terminated(take_while1("a map name", |c| c != '\n'), tag("\n"));
+    let block = map(preceded(header, lines(mapper)), |mappers| MapperBlock {
+        mappers,
+    });
+    let (seeds, mapper_blocks) = parse_all(
+        content,
+        pair(terminated(seeds, tag("\n\n")), sections(block)),
+    )?;
+    return Ok((seeds, MapperPipeline { mapper_blocks }));
+}
+
+/// Parse without solving, for the fuzz targets.
+pub fn validate(content: &str) -> Result<(), Error> {
+    return parse(content).map(|_| ());
+}
+
+fn solve(content: &str) -> Result<i64, Error> {
+    let (seeds, pipeline) = parse(content)?;
+    return Ok(seeds
+        .iter()
+        .map(|seed| pipeline.get_last(*seed))
+        .min()
+        .unwrap());
+}
+
+pub fn run(content: &str) -> Result<Answer, Error> {
+    let result = solve(content)?;
+    return Ok(result.into());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test() {
+        let content = String::from(
+            "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4",
+        );
+        let result = solve(&content).unwrap();
+        assert_eq!(result, 35);
+    }
+
+    #[test]
+    fn test_bad_input() {
+        for content in [
+            "",
+            "seeds: 79 14",
+            "seeds:\n\nmap:\n1 2 3",
+            "seeds: x\n\nmap:\n1 2 3",
+            "seeds: 1\n\nmap:\n1 2",
+            "seeds: 1\n\nmap:\n9223372036854775807 0 1",
+        ] {
+            assert!(validate(content).is_err(), "{content:?}");
+        }
+    }
+}
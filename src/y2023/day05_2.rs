@@ -0,0 +1,294 @@
+use crate::{
+    certificate::{self, Certificate},
+    interval::{Interval, IntervalSet},
+    parser::{
+        ints, key_value, lines, map, pair, parse_all, preceded, sections, tag, take_while1,
+        terminated, try_map,
+    },
+    Answer, Error, Facts,
+};
+
+/// Mapper-related ///
+struct Mapper {
+    source: Interval,
+    dest: Interval,
+}
+impl Mapper {
+    /// Where the part of the source range `part` ends up.
+    fn map(&self, part: Interval) -> Interval {
+        return Interval::new(self.map_point(part.lo), self.map_point(part.hi));
+    }
+    fn map_point(&self, x: i64) -> i64 {
+        // dest is as long as source, so the result fits even when the offset alone would not
+        return (self.dest.lo as i128 + (x as i128 - self.source.lo as i128)) as i64;
+    }
+}
+/// Numbers some seed range ended up at, and the seed that ended up at the start.
+struct Piece {
+    range: Interval,
+    seed: i64,
+}
+impl Piece {
+    fn seed_at(&self, x: i64) -> i64 {
+        return (self.seed as i128 + (x as i128 - self.range.lo as i128)) as i64;
+    }
+}
+struct MapperBlock {
+    mappers: Vec<Mapper>,
+}
+impl MapperBlock {
+    fn remap(&self, seeds: &IntervalSet) -> IntervalSet {
+        // the first mapper that covers a number moves it, numbers no mapper covers stay put
+        let mut moved = IntervalSet::new();
+        let mut unmapped = seeds.clone();
+        for mapper in self.mappers.iter() {
+            let source = IntervalSet::from_iter([mapper.source]);
+            for part in unmapped.intersection(&source).iter() {
+                moved.insert(mapper.map(part));
+            }
+            unmapped = unmapped.difference(&source);
+        }
+        return moved.union(&unmapped);
+    }
+    /// Where the single number `x` ends up, the way the puzzle describes it.
+    fn remap_point(&self, x: i64) -> i64 {
+        return match self.mappers.iter().find(|mapper| mapper.source.contains(x)) {
+            Some(mapper) => mapper.map_point(x),
+            None => x,
+        };
+    }
+    /// Same as [`MapperBlock::remap`], keeping track of the seed at the start of every piece.
+    fn remap_pieces(&self, pieces: Vec<Piece>) -> Vec<Piece> {
+        let mut moved = vec![];
+        let mut unmapped = pieces;
+        for mapper in self.mappers.iter() {
+            let mut rest = vec![];
+            for piece in unmapped {
+                if let Some(part) = piece.range.intersection(mapper.source) {
+                    moved.push(Piece {
+                        range: mapper.map(part),
+                        seed: piece.seed_at(part.lo),
+                    });
+                }
+                for part in piece.range.difference(mapper.source).into_iter().flatten() {
+                    rest.push(Piece {
+                        range: part,
+                        seed: piece.seed_at(part.lo),
+                    });
+                }
+            }
+            unmapped = rest;
+        }
+        moved.extend(unmapped);
+        return moved;
+    }
+}
+struct MapperPipeline {
+    mapper_blocks: Vec<MapperBlock>,
+}
+impl MapperPipeline {
+    /// The seed that ends up at `location`, following the seeds forward: going backwards
+    /// cannot tell a number a map moved from one it left alone.
+    fn get_seed(&self, seeds: &IntervalSet, location: i64) -> Option<i64> {
+        let mut pieces = seeds
+            .iter()
+            .map(|range| Piece {
+                range,
+                seed: range.lo,
+            })
+            .collect::<Vec<_>>();
+        for block in self.mapper_blocks.iter() {
+            pieces = block.remap_pieces(pieces);
+        }
+        let piece = pieces.iter().find(|piece| piece.range.contains(location))?;
+        return Some(piece.seed_at(location));
+    }
+    fn run(&self, seeds: &IntervalSet) -> IntervalSet {
+        let mut ranges = seeds.clone();
+        for block in self.mapper_blocks.iter() {
+            ranges = block.remap(&ranges);
+        }
+        return ranges;
+    }
+}
+
+fn parse(content: &str) -> Result<(IntervalSet, MapperPipeline), Error> {
+    let seeds = try_map(ints::<i64>(), |nums| {
+        if nums.len() % 2 != 0 {
+            return Err(String::from("seeds in (start, length) pairs"));
+        }
+        return nums
+            .chunks(2)
+            .map(|chunk| Interval::with_len(chunk[0], chunk[1]))
+            .collect::<Option<IntervalSet>>()
+            .ok_or(String::from(
+                "seed ranges that are non-empty and fit an i64",
+            ));
+    });
+    let mapper = try_map(ints::<i64>(), |nums| {
+        let [dest, source, range] = nums[..] else {
+            return Err(format!("3 numbers, not {}", nums.len()));
+        };
+        let (Some(source), Some(dest)) = (
+            Interval::with_len(source, range),
+            Interval::with_len(dest, range),
+        ) else {
+            return Err(String::from("a non-empty range that fits an i64"));
+        };
+        return Ok(Mapper { source, dest });
+    });
+    let header = terminated(take_while1("a map name", |c| c != '\n'), tag("\n"));
+    let block = map(preceded(header, lines(mapper)), |mappers| MapperBlock {
+        mappers,
+    });
+    let (seeds, mapper_blocks) = parse_all(
+        content,
+        pair(
+            terminated(key_value("seeds", seeds), tag("\n\n")),
+            sections(block),
+        ),
+    )?;
+    return Ok((seeds, MapperPipeline { mapper_blocks }));
+}
+
+/// Parse without solving, for the fuzz targets.
+pub fn validate(content: &str) -> Result<(), Error> {
+    return parse(content).map(|_| ());
+}
+
+/// The lowest location and the seed that ends up there.
+fn solve(content: &str, facts: &mut Facts) -> Result<(i64, i64), Error> {
+    let (seeds, pipeline) = parse(content)?;
+    facts.note("seed ranges", seeds.iter().count());
+    let locations = pipeline.run(&seeds);
+    facts.note("location ranges", locations.iter().count());
+    let minimum_location = locations.min().unwrap();
+    facts.note("lowest location", minimum_location);
+    let seed = pipeline
+        .get_seed(&seeds, minimum_location)
+        .expect("the lowest location comes from some seed");
+    facts.note("winning seed", seed);
+    return Ok((minimum_location, seed));
+}
+
+pub fn run(content: &str, facts: &mut Facts) -> Result<Answer, Error> {
+    // the main idea is to push whole seed ranges through the maps instead of single seeds. every map cuts the
+    // ranges at the edges of its mappers and shifts the pieces, the answer is the start of the lowest range.
+    let (result, _) = solve(content, facts)?;
+    return Ok(result.into());
+}
+
+pub fn certify(content: &str) -> Result<(Answer, Certificate), Error> {
+    let (result, seed) = solve(content, &mut Facts::new())?;
+    return Ok((result.into(), Certificate::Witness(seed)));
+}
+
+/// The seed is in one of the seed ranges and one map at a time takes it to the answer.
+pub fn check(content: &str, answer: &Answer, certificate: &Certificate) -> Result<(), Error> {
+    let (seeds, pipeline) = parse(content)?;
+    let &Certificate::Witness(seed) = certificate else {
+        return Err(Error::rejected("not a seed"));
+    };
+    if !seeds.contains(seed) {
+        return Err(Error::rejected(format!("{seed} is not in any seed range")));
+    }
+    let location = pipeline
+        .mapper_blocks
+        .iter()
+        .fold(seed, |x, block| block.remap_point(x));
+    return certificate::claims(answer, location);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test() {
+        let content = String::from(
+            "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4",
+        );
+        let mut facts = Facts::new();
+        let (result, _) = solve(&content, &mut facts).unwrap();
+        assert_eq!(result, 46);
+        assert_eq!(facts.get("winning seed"), Some("82"));
+
+        let (answer, certificate) = certify(&content).unwrap();
+        assert_eq!(certificate, Certificate::Witness(82));
+        assert_eq!(check(&content, &answer, &certificate), Ok(()));
+        // seed 79 is in range, but ends up at 82
+        let worse = Certificate::Witness(79);
+        assert_eq!(
+            check(&content, &answer, &worse),
+            Err(Error::rejected("it adds up to 82, not 46"))
+        );
+        let outside = Certificate::Witness(13);
+        assert!(check(&content, &Answer::Int(13), &outside).is_err());
+    }
+
+    #[test]
+    fn test_unmapped_seed() {
+        // 5 goes through the map as is, even though 105 would be mapped to 5
+        let content = "seeds: 5 1\n\nseed-to-soil map:\n0 100 10\n";
+        let mut facts = Facts::new();
+        assert_eq!(solve(content, &mut facts).unwrap(), (5, 5));
+        assert_eq!(facts.get("winning seed"), Some("5"));
+    }
+
+    #[test]
+    fn test_certify_unmapped() {
+        // the lowest location is seed 5, which no mapper moves, 50 goes up to 60
+        let content = "seeds: 5 1 50 1\n\nseed-to-soil map:\n0 100 10\n60 50 1";
+        let (answer, certificate) = certify(content).unwrap();
+        assert_eq!(
+            (&answer, &certificate),
+            (&Answer::Int(5), &Certificate::Witness(5))
+        );
+        assert_eq!(check(content, &answer, &certificate), Ok(()));
+    }
+
+    #[test]
+    fn test_bad_input() {
+        for content in [
+            "",
+            "seeds: 79 14",
+            "seeds: 79\n\nmap:\n1 2 3",
+            "seeds: 79 -9223372036854775808\n\nmap:\n1 2 3",
+            "seeds: 79 14\n\nmap:\n1 2 3 4",
+            "seeds: 79 14\n\nmap:\n9223372036854775807 0 2",
+        ] {
+            assert!(validate(content).is_err(), "{content:?}");
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{
+    parser::{ints, key_value, pair, parse_all, tag, terminated},
+    Answer, Error,
+};
+
+#[derive(Debug)]
+struct Game {
+    time: i32,
+    dist: i32,
+}
+
+fn parse(content: &str) -> Result<Vec<Game>, Error> {
+    let grammar = pair(
+        terminated(key_value("Time", ints::<i32>()), tag("\n")),
+        key_value("Distance", ints::<i32>()),
+    );
+    let (times, distances) = parse_all(content, grammar)?;
+    if times.len() != distances.len() {
+        return Err(Error::parse(format!(
+            "{} times but {} distances",
+            times.len(),
+            distances.len()
+        )));
+    }
+    return Ok(times
+        .into_iter()
+        .zip(distances)
+        .map(|(time, dist)| Game { time, dist })
+        .collect());
+}
+
+fn calculate_number_of_ways(game: Game) -> i32 {
+    // i think looping from the middle is a good strat
+    let mut wins = 0;
+    for i in (0..(game.time / 2 + 1)).rev() {
+        if i * (game.time - i) > game.dist {
+            wins += 1;
+        } else {
+            break;
+        }
+    }
+    // an even time has a single middle, the one that got counted twice
+    match (wins, game.time % 2 == 0) {
+        (0, _) => 0,
+        (_, true) => wins * 2 - 1,
+        (_, false) => wins * 2,
+    }
+}
+
+fn solve(content: &str) -> Result<i32, Error> {
+    let games = parse(content)?;
+    return Ok(games.into_iter().map(calculate_number_of_ways).product());
+}
+
+pub fn run(content: &str) -> Result<Answer, Error> {
+    let result = solve(content)?;
+    return Ok(result.into());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test() {
+        let content = String::from(
+            "Time:      7  15   30
+Distance:  9  40  200",
+        );
+        let result = solve(&content).unwrap();
+        assert_eq!(result, 288);
+    }
+
+    #[test]
+    fn test_bad_input() {
+        for content in [
+            "",
+            "Time: 7\n",
+            "Time: 7\nDist: 9",
+            "Time: x\nDistance: 9",
+            "Time: 7 15\nDistance: 9",
+        ] {
+            assert!(solve(content).is_err(), "{content:?}");
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn prop_matches_loop(time in 0..300, dist in 0..25000) {
+            let expected = (0..=time).filter(|i| i * (time - i) > dist).count() as i32;
+            prop_assert_eq!(calculate_number_of_ways(Game { time, dist }), expected);
+        }
+    }
+}
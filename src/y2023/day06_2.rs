@@ -0,0 +1,93 @@
+use crate::{
+    parser::{digits, key_value, list, pair, parse_all, space1, tag, terminated, try_map},
+    Answer, Error,
+};
+
+#[derive(Debug)]
+struct Game {
+    time: i64,
+    dist: i64,
+}
+
+fn parse(content: &str) -> Result<Game, Error> {
+    // the spaces between the digits are bad kerning, it is one number
+    let number = || {
+        try_map(list(digits(), space1()), |digits| {
+            return digits
+                .concat()
+                .parse::<i64>()
+                .map_err(|_| String::from("a smaller number"));
+        })
+    };
+    let grammar = pair(
+        terminated(key_value("Time", number()), tag("\n")),
+        key_value("Distance", number()),
+    );
+    let (time, dist) = parse_all(content, grammar)?;
+    return Ok(Game { time, dist });
+}
+
+fn calculate_number_of_ways(game: Game) -> i64 {
+    // i think looping from the middle is a good strat
+    let mut wins = 0;
+    for i in (0..(game.time / 2 + 1)).rev() {
+        if i * (game.time - i) > game.dist {
+            wins += 1;
+        } else {
+            break;
+        }
+    }
+    // an even time has a single middle, the one that got counted twice
+    match (wins, game.time % 2 == 0) {
+        (0, _) => 0,
+        (_, true) => wins * 2 - 1,
+        (_, false) => wins * 2,
+    }
+}
+
+fn solve(content: &str) -> Result<i64, Error> {
+    let game = parse(content)?;
+    return Ok(calculate_number_of_ways(game));
+}
+
+pub fn run(content: &str) -> Result<Answer, Error> {
+    let result = solve(content)?;
+    return Ok(result.into());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test() {
+        let content = String::from(
+            "Time:      7  15   30
+Distance:  9  40  200",
+        );
+        let result = solve(&content).unwrap();
+        assert_eq!(result, 71503);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_matches_loop(time in 0i64..5000, dist in 0i64..7_000_000) {
+            let expected = (0..=time).filter(|i| i * (time - i) > dist).count() as i64;
+            prop_assert_eq!(calculate_number_of_ways(Game { time, dist }), expected);
+        }
+    }
+
+    #[test]
+    fn test_bad_input() {
+        for content in [
+            "",
+            "Time: 7\n",
+            "Time: 7\nDist: 9",
+            "Time: x\nDistance: 9",
+            "Time: 99999999999 99999999999\nDistance: 9",
+        ] {
+            assert!(solve(content).is_err(), "{content:?}");
+        }
+    }
+}
@@ -1,7 +1,10 @@
-use std::{collections::HashMap, fs, path::Path};
+use std::collections::HashMap;
+
+use crate::{parse_num, Answer, Error};
 
 #[derive(Debug)]
 struct Hand {
+    #[allow(dead_code)]
     cards: String,
     bid: i32,
     cards_type: i32,       // this is computed
@@ -18,7 +21,7 @@ impl Hand {
             cards_power,
         }
     }
-    fn compute_cards_power(cards: &String) -> Vec<i32> {
+    fn compute_cards_power(cards: &str) -> Vec<i32> {
         // this is extremely ineffective
         let power_mapping: HashMap<char, i32> = HashMap::from([
             ('A', 12),
@@ -37,7 +40,7 @@ impl Hand {
         ]);
         cards.chars().map(|c| power_mapping[&c]).collect()
     }
-    fn compute_cards_type(cards: &String) -> i32 {
+    fn compute_cards_type(cards: &str) -> i32 {
         // cards_type list:
         // 5 of a kind -> 6
         // 4 of a kind -> 5
@@ -85,30 +88,39 @@ impl Hand {
     }
 }
 
-fn parse(content: &String) -> Vec<Hand> {
-    content
-        .lines()
-        .map(|x| x.split_once(" ").unwrap())
-        .map(|(cards, bid)| Hand::new(cards.to_string(), bid.parse().unwrap()))
-        .collect()
+fn parse(content: &str) -> Result<Vec<Hand>, Error> {
+    let mut hands = vec![];
+    for line in content.lines() {
+        let (cards, bid) = line
+            .split_once(" ")
+            .ok_or_else(|| Error::parse(format!("expected `<cards> <bid>`: {line:?}")))?;
+        if cards.len() != 5 || !cards.chars().all(|c| "AKQJT98765432".contains(c)) {
+            return Err(Error::parse(format!("bad hand: {cards:?}")));
+        }
+        hands.push(Hand::new(cards.to_string(), parse_num(bid)?));
+    }
+    return Ok(hands);
+}
+
+/// Parse without solving, for the fuzz targets.
+pub fn validate(content: &str) -> Result<(), Error> {
+    return parse(content).map(|_| ());
 }
 
-fn solve(content: String) -> i32 {
-    let mut hands = parse(&content);
+fn solve(content: &str) -> Result<i32, Error> {
+    let mut hands = parse(content)?;
     // rust tuple implements lexicographic comparison
     hands.sort_by_key(|hand| (hand.cards_type, hand.cards_power.clone())); // oh no clone here is super ineffective
-    hands
+    return Ok(hands
         .iter()
         .enumerate()
         .map(|(i, hand)| (i as i32 + 1) * hand.bid)
-        .sum()
+        .sum());
 }
 
-fn main() {
-    let content = fs::read_to_string(Path::new("inputs/day07.txt"))
-        .expect("input for day 7 is missing.");
-    let result = solve(content);
-    println!("day 7 part 1: {}", result);
+pub fn run(content: &str) -> Result<Answer, Error> {
+    let result = solve(content)?;
+    return Ok(result.into());
 }
 
 #[cfg(test)]
@@ -123,7 +135,21 @@ KK677 28
 KTJJT 220
 QQQJA 483",
         );
-        let result = solve(content);
+        let result = solve(&content).unwrap();
         assert_eq!(result, 6440);
     }
+
+    #[test]
+    fn test_bad_input() {
+        for content in [
+            "32T3K",
+            "32T3K x",
+            "32T3 765",
+            "32T3KK 765",
+            "32X3K 765",
+            "3é3K 765",
+        ] {
+            assert!(validate(content).is_err(), "{content:?}");
+        }
+    }
 }
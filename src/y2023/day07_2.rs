@@ -1,4 +1,6 @@
-use std::{collections::HashMap, fs, path::Path};
+use std::collections::HashMap;
+
+use crate::{parse_num, Answer, Error};
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 enum Face {
@@ -56,10 +58,8 @@ impl Hand {
                 'Q' => Face::Q,
                 'K' => Face::K,
                 'A' => Face::A,
-                sus => {
-                    println!("{}", sus);
-                    unreachable!()
-                }
+                // the parser only lets these through
+                sus => unreachable!("card {sus:?}"),
             })
             .collect()
     }
@@ -99,30 +99,39 @@ impl Hand {
     }
 }
 
-fn parse(content: &String) -> Vec<Hand> {
-    content
-        .lines()
-        .map(|x| x.split_once(" ").unwrap())
-        .map(|(cards, bid)| Hand::new(cards, bid.parse().unwrap()))
-        .collect()
+fn parse(content: &str) -> Result<Vec<Hand>, Error> {
+    let mut hands = vec![];
+    for line in content.lines() {
+        let (cards, bid) = line
+            .split_once(" ")
+            .ok_or_else(|| Error::parse(format!("expected `<cards> <bid>`: {line:?}")))?;
+        if cards.len() != 5 || !cards.chars().all(|c| "AKQJT98765432".contains(c)) {
+            return Err(Error::parse(format!("bad hand: {cards:?}")));
+        }
+        hands.push(Hand::new(cards, parse_num(bid)?));
+    }
+    return Ok(hands);
+}
+
+/// Parse without solving, for the fuzz targets.
+pub fn validate(content: &str) -> Result<(), Error> {
+    return parse(content).map(|_| ());
 }
 
-fn solve(content: String) -> i32 {
-    let mut hands = parse(&content);
+fn solve(content: &str) -> Result<i32, Error> {
+    let mut hands = parse(content)?;
     // rust tuple implements lexicographic comparison
     hands.sort_by(|a, b| a.tier.cmp(&b.tier).then_with(|| a.faces.cmp(&b.faces)));
-    hands
+    return Ok(hands
         .iter()
         .enumerate()
         .map(|(i, hand)| (i as i32 + 1) * hand.bid)
-        .sum()
+        .sum());
 }
 
-fn main() {
-    let content = fs::read_to_string(Path::new("inputs/day07.txt"))
-        .expect("input for day 7 is missing.");
-    let result = solve(content);
-    println!("day 7 part 2: {}", result);
+pub fn run(content: &str) -> Result<Answer, Error> {
+    let result = solve(content)?;
+    return Ok(result.into());
 }
 
 #[cfg(test)]
@@ -137,7 +146,21 @@ KK677 28
 KTJJT 220
 QQQJA 483",
         );
-        let result = solve(content);
+        let result = solve(&content).unwrap();
         assert_eq!(result, 5905);
     }
+
+    #[test]
+    fn test_bad_input() {
+        for content in [
+            "32T3K",
+            "32T3K x",
+            "32T3 765",
+            "32T3KK 765",
+            "32X3K 765",
+            "3é3K 765",
+        ] {
+            assert!(validate(content).is_err(), "{content:?}");
+        }
+    }
 }
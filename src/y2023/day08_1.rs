@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+
+use crate::{Answer, Error};
+
+type Network = HashMap<String, (String, String)>;
+
+fn parse(content: &str) -> Result<(Vec<char>, Network), Error> {
+    let (steps, directions) = content
+        .split_once("\n\n")
+        .ok_or(Error::parse("missing blank line after the steps"))?;
+    let steps = steps.chars().collect::<Vec<_>>();
+    if steps.is_empty() || steps.iter().any(|&c| c != 'L' && c != 'R') {
+        return Err(Error::parse(format!("steps must be L or R: {steps:?}")));
+    }
+    let mut parsed = HashMap::new();
+    for x in directions.lines() {
+        // AAA = (BBB, CCC)
+        let is_name = |name: &str| name.chars().all(|c| c.is_ascii_alphanumeric());
+        let well_formed = x.len() == 16
+            && x.is_ascii()
+            && is_name(&x[0..3])
+            && &x[3..7] == " = ("
+            && is_name(&x[7..10])
+            && &x[10..12] == ", "
+            && is_name(&x[12..15])
+            && &x[15..] == ")";
+        if !well_formed {
+            return Err(Error::parse(format!("expected `AAA = (BBB, CCC)`: {x:?}")));
+        }
+        parsed.insert(
+            x[0..3].to_string(),
+            (x[7..10].to_string(), x[12..15].to_string()),
+        );
+    }
+    for (left, right) in parsed.values() {
+        if !parsed.contains_key(left) || !parsed.contains_key(right) {
+            return Err(Error::parse(format!("unknown node in ({left}, {right})")));
+        }
+    }
+    return Ok((steps, parsed));
+}
+
+/// Parse without solving, for the fuzz targets.
+pub fn validate(content: &str) -> Result<(), Error> {
+    return parse(content).map(|_| ());
+}
+
+fn solve(content: &str) -> Result<i32, Error> {
+    let (steps, directions) = parse(content)?;
+    // do walking simulation
+    if !directions.contains_key("AAA") {
+        return Err(Error::parse("there is no AAA node"));
+    }
+    let mut curr = &String::from("AAA");
+    let finish = &String::from("ZZZ");
+    let mut counter = 0;
+    for step in steps.into_iter().cycle() {
+        if curr == finish {
+            break;
+        }
+        counter += 1;
+        curr = match step {
+            'L' => &directions[curr].0,
+            'R' => &directions[curr].1,
+            _ => unreachable!(),
+        }
+    }
+    return Ok(counter);
+}
+
+pub fn run(content: &str) -> Result<Answer, Error> {
+    let result = solve(content)?;
+    return Ok(result.into());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{solve, validate};
+    #[test]
+    fn test1() {
+        let content = String::from(
+            "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)",
+        );
+        let result = solve(&content).unwrap();
+        assert_eq!(result, 2);
+    }
+    #[test]
+    fn test2() {
+        let content = String::from(
+            "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)",
+        );
+        let result = solve(&content).unwrap();
+        assert_eq!(result, 6);
+    }
+
+    #[test]
+    fn test_bad_input() {
+        for content in [
+            "LR",
+            "LX\n\nAAA = (AAA, AAA)",
+            "LR\n\nAAA = (AAA, AAA",
+            "LR\n\nAAA = (BBB, AAA)",
+            "LR\n\nAAA=(AAA,AAA)xxxx",
+            "LR\n\nAAé = (AAA, AA)",
+        ] {
+            assert!(validate(content).is_err(), "{content:?}");
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+
+use crate::{number_theory::crt, Answer, Error};
+
+/// Steps until the walk first stands on a node ending with Z, and until it does so again.
+fn simulate_walk(
+    starting_node: &str,
+    steps: &[char],
+    directions: &HashMap<String, (String, String)>,
+) -> (i64, i64) {
+    // do walking simulation
+    let mut curr = starting_node;
+    let mut hits = vec![];
+    for (counter, step) in steps.iter().cycle().enumerate() {
+        if curr.ends_with("Z") {
+            hits.push(counter as i64);
+            if hits.len() == 2 {
+                break;
+            }
+        }
+        curr = match step {
+            'L' => &directions[curr].0,
+            'R' => &directions[curr].1,
+            _ => unreachable!(),
+        }
+    }
+    return (hits[0], hits[1]);
+}
+
+type Network = HashMap<String, (String, String)>;
+
+fn parse(content: &str) -> Result<(Vec<char>, Network), Error> {
+    let (steps, directions) = content
+        .split_once("\n\n")
+        .ok_or(Error::parse("missing blank line after the steps"))?;
+    let steps = steps.chars().collect::<Vec<_>>();
+    if steps.is_empty() || steps.iter().any(|&c| c != 'L' && c != 'R') {
+        return Err(Error::parse(format!("steps must be L or R: {steps:?}")));
+    }
+    let mut parsed = HashMap::new();
+    for x in directions.lines() {
+        // AAA = (BBB, CCC)
+        let is_name = |name: &str| name.chars().all(|c| c.is_ascii_alphanumeric());
+        let well_formed = x.len() == 16
+            && x.is_ascii()
+            && is_name(&x[0..3])
+            && &x[3..7] == " = ("
+            && is_name(&x[7..10])
+            && &x[10..12] == ", "
+            && is_name(&x[12..15])
+            && &x[15..] == ")";
+        if !well_formed {
+            return Err(Error::parse(format!("expected `AAA = (BBB, CCC)`: {x:?}")));
+        }
+        parsed.insert(
+            x[0..3].to_string(),
+            (x[7..10].to_string(), x[12..15].to_string()),
+        );
+    }
+    for (left, right) in parsed.values() {
+        if !parsed.contains_key(left) || !parsed.contains_key(right) {
+            return Err(Error::parse(format!("unknown node in ({left}, {right})")));
+        }
+    }
+    return Ok((steps, parsed));
+}
+
+/// Parse without solving, for the fuzz targets.
+pub fn validate(content: &str) -> Result<(), Error> {
+    return parse(content).map(|_| ());
+}
+
+fn solve(content: &str) -> Result<i64, Error> {
+    let (steps, directions) = parse(content)?;
+    // every ghost stands on a Z node after `first` steps and again every `cycle` steps after that,
+    // assuming it only ever loops through the one Z node
+    let mut congruences = vec![];
+    let mut latest_first = 0;
+    for start in directions.keys().filter(|x| x.ends_with("A")) {
+        let (first, second) = simulate_walk(start, &steps, &directions);
+        congruences.push((first as i128, (second - first) as i128));
+        latest_first = latest_first.max(first as i128);
+    }
+    // the ghosts meet at the smallest x that is on every ghost's cycle, usually just the lcm of the cycles
+    let (mut x, modulus) =
+        crt(&congruences).ok_or(Error::parse("the ghosts never stand on Z nodes together"))?;
+    // a solution before a ghost's first Z does not count
+    if x < latest_first {
+        x += (latest_first - x + modulus - 1) / modulus * modulus;
+    }
+    return i64::try_from(x).map_err(|_| Error::parse("the answer does not fit an i64"));
+}
+
+pub fn run(content: &str) -> Result<Answer, Error> {
+    let result = solve(content)?;
+    return Ok(result.into());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{solve, validate};
+    #[test]
+    fn test() {
+        let content = String::from(
+            "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)",
+        );
+        let result = solve(&content).unwrap();
+        assert_eq!(result, 6);
+    }
+    #[test]
+    fn test_offsets() {
+        // ghost 1 is on 11Z after 1, 4, 7.. steps, ghost 2 on 22Z after 2, 4, 6.. steps
+        let content = "L
+
+11A = (11Z, 11Z)
+11Z = (11B, 11B)
+11B = (11C, 11C)
+11C = (11Z, 11Z)
+22A = (22B, 22B)
+22B = (22Z, 22Z)
+22Z = (22C, 22C)
+22C = (22Z, 22Z)";
+        assert_eq!(solve(content).unwrap(), 4);
+    }
+
+    #[test]
+    fn test_bad_input() {
+        for content in [
+            "LR",
+            "LX\n\nAAA = (AAA, AAA)",
+            "LR\n\nAAA = (AAA, AAA",
+            "LR\n\nAAA = (BBB, AAA)",
+            "LR\n\nAAA=(AAA,AAA)xxxx",
+            "LR\n\nAAé = (AAA, AA)",
+        ] {
+            assert!(validate(content).is_err(), "{content:?}");
+        }
+    }
+}
@@ -1,6 +1,8 @@
-use std::{fs, path::Path, vec};
+use std::vec;
 
-fn parse(content: &String) -> Vec<Vec<i32>> {
+use crate::{Answer, Error};
+
+fn parse(content: &str) -> Vec<Vec<i32>> {
     content
         .lines()
         .map(|line| {
@@ -11,7 +13,7 @@ fn parse(content: &String) -> Vec<Vec<i32>> {
         .collect()
 }
 
-fn predict_leftmost(nums: &Vec<i32>) -> i32 {
+fn predict_leftmost(nums: &[i32]) -> i32 {
     // idk man i felt that recursive algo is very natural
     if nums.iter().all(|&x| x == 0) {
         // this assumes that eventually vectors will be all 0 before running out of elements
@@ -25,7 +27,7 @@ fn predict_leftmost(nums: &Vec<i32>) -> i32 {
     return diff.first().unwrap() - predicted;
 }
 
-fn solve(content: &String) -> i32 {
+fn solve(content: &str) -> i32 {
     let nums_list = parse(content);
     nums_list
         .iter()
@@ -33,16 +35,19 @@ fn solve(content: &String) -> i32 {
         .sum()
 }
 
-fn main() {
-    let content =
-        fs::read_to_string(Path::new("inputs/day09.txt")).expect("input for day 9 is missing");
-    let result = solve(&content);
-    println!("day 9 part 2: {}", result);
+pub fn run(content: &str) -> Result<Answer, Error> {
+    let result = solve(content);
+    return Ok(result.into());
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::solve;
+    use super::{predict_leftmost, solve};
+    use proptest::prelude::*;
+
+    fn eval(coefs: &[i32], x: i32) -> i32 {
+        coefs.iter().rev().fold(0, |acc, c| acc * x + c)
+    }
 
     #[test]
     fn test() {
@@ -54,4 +59,15 @@ mod tests {
         let result = solve(&content);
         assert_eq!(result, 2);
     }
+
+    proptest! {
+        // any integer polynomial of degree d is extrapolated exactly from d + 2 values
+        #[test]
+        fn prop_polynomial(coefs in prop::collection::vec(-10..10, 1..6), extra in 0..5usize) {
+            let n = coefs.len() + 1 + extra;
+            let nums = (0..n as i32).map(|x| eval(&coefs, x)).collect::<Vec<_>>();
+            let prev = nums.first().unwrap() - predict_leftmost(&nums);
+            prop_assert_eq!(prev, eval(&coefs, -1));
+        }
+    }
 }
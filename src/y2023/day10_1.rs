@@ -0,0 +1,221 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::{
+    dump,
+    dump::Level,
+    geometry::{Dir, Pos},
+    Answer, Error, Facts, Grid,
+};
+
+/// Directions a pipe connects to.
+fn pipe_dirs(pipe: u8) -> &'static [Dir] {
+    match pipe {
+        b'|' => &[Dir::Up, Dir::Down],
+        b'-' => &[Dir::Left, Dir::Right],
+        b'F' => &[Dir::Down, Dir::Right],
+        b'7' => &[Dir::Down, Dir::Left],
+        b'L' => &[Dir::Up, Dir::Right],
+        b'J' => &[Dir::Up, Dir::Left],
+        _ => &[],
+    }
+}
+
+fn parse(content: &str) -> Result<(Grid<u8>, Pos), Error> {
+    let mut pipes = Grid::parse_bytes(content.as_bytes(), |c| {
+        b"|-LJ7F.S".contains(&c).then_some(c)
+    })?;
+    if pipes.iter().filter(|(_, &c)| c == b'S').count() != 1 {
+        return Err(Error::parse("there must be exactly one S"));
+    }
+    let start = pipes.find(&b'S').unwrap();
+    // replace S with the pipe that connects to both of its connected neighbours
+    let connected = Dir::ALL
+        .into_iter()
+        .filter(|&dir| {
+            pipes
+                .get(start.step(dir))
+                .is_some_and(|&next| pipe_dirs(next).contains(&dir.opposite()))
+        })
+        .collect::<Vec<_>>();
+    let Some(pipe) = b"|-F7LJ"
+        .iter()
+        .copied()
+        .find(|&pipe| pipe_dirs(pipe).iter().all(|dir| connected.contains(dir)))
+    else {
+        return Err(Error::parse(format!(
+            "S at {start:?} does not connect to two pipes"
+        )));
+    };
+    pipes[start] = pipe;
+    return Ok((pipes, start));
+}
+
+/// Parse without solving, for the fuzz targets.
+pub fn validate(content: &str) -> Result<(), Error> {
+    return parse(content).map(|_| ());
+}
+
+fn make_debug_map_like(pipes: &Grid<u8>) -> Grid<i32> {
+    return Grid::new(pipes.h(), pipes.w(), -1);
+}
+
+fn debug_print(debug: &Grid<i32>) {
+    let biggest_ndigits = debug
+        .iter()
+        .map(|(_, num)| match num {
+            -1 => 1,
+            _ => num.to_string().len(),
+        })
+        .max()
+        .unwrap();
+    let mut out = String::new();
+    for row in debug.rows() {
+        for &num in row {
+            let what = if num == -1 {
+                "-".to_string()
+            } else {
+                num.to_string()
+            };
+            out += &format!("{:width$}", what, width = biggest_ndigits + 1);
+        }
+        out += "\n";
+    }
+    dump!(Debug, "distances\n{out}");
+}
+
+fn bfs(pipes: &Grid<u8>, starting_coord: Pos) -> i32 {
+    let mut debug = make_debug_map_like(pipes);
+    let mut visited = HashSet::<Pos>::new();
+    let mut queue = VecDeque::<(Pos, i32)>::new();
+    let bounds = pipes.bounds();
+    queue.push_back((starting_coord, 0));
+    let mut farthest = -1;
+    while !queue.is_empty() {
+        let (coord, dist) = queue.pop_front().unwrap();
+        visited.insert(coord);
+        debug[coord] = dist;
+        farthest = dist; // in bfs it is guaranteed that the last in queue is the farthest
+
+        // add the neighbour according to the pipe types, as long as it is on the grid
+        for &direction in pipe_dirs(pipes[coord]) {
+            let Some(new_coord) = bounds.neighbour(coord, direction) else {
+                continue;
+            };
+            // visit checks
+            if visited.contains(&new_coord) {
+                continue;
+            }
+            // '.' checks
+            if pipes[new_coord] == b'.' {
+                continue;
+            }
+            // finally: can add neighbour
+            queue.push_back((new_coord, dist + 1));
+        }
+    }
+    if dump::enabled(Level::Debug) {
+        debug_print(&debug);
+    }
+    return farthest;
+}
+
+fn solve(content: &str) -> Result<i32, Error> {
+    let (pipes, starting_coord) = parse(content)?;
+    let farthest = bfs(&pipes, starting_coord);
+    return Ok(farthest);
+}
+
+pub fn run(content: &str) -> Result<Answer, Error> {
+    let result = solve(content)?;
+    return Ok(result.into());
+}
+
+pub fn inspect(content: &str) -> Result<Facts, Error> {
+    let (pipes, start) = parse(content)?;
+    let mut facts = Facts::new();
+    facts.note("rows", pipes.h());
+    facts.note("columns", pipes.w());
+    facts.note("start", format!("{},{}", start.y, start.x));
+    facts.note("pipes", pipes.iter().filter(|(_, &c)| c != b'.').count());
+    return Ok(facts);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, solve, validate, Pos};
+
+    #[test]
+    fn test_location() {
+        let content = String::from(
+            "..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...",
+        );
+        let (_, starting_coord) = parse(&content).unwrap();
+        assert_eq!(starting_coord, Pos::new(2, 0));
+    }
+
+    #[test]
+    fn test1() {
+        let content = String::from(
+            "..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...",
+        );
+        let result = solve(&content).unwrap();
+        assert_eq!(result, 8);
+    }
+
+    #[test]
+    fn test2() {
+        let content = String::from(
+            ".....
+.S-7.
+.|.|.
+.L-J.
+.....",
+        );
+        let result = solve(&content).unwrap();
+        assert_eq!(result, 4);
+    }
+
+    #[test]
+    fn test3() {
+        let content = String::from(
+            ".....
+.S-7.
+.|.|.
+.L-J.
+.....",
+        );
+        let (pipes, _) = parse(&content).unwrap();
+        assert_eq!(pipes[Pos::new(1, 1)], b'F');
+    }
+
+    #[test]
+    fn test_start_in_column_1() {
+        // S connects to the pipe in column 0, which the old bounds check missed
+        let content = "FS7\n|.|\nL-J";
+        let (pipes, _) = parse(content).unwrap();
+        assert_eq!(pipes[Pos::new(0, 1)], b'-');
+        assert_eq!(solve(content).unwrap(), 4);
+    }
+
+    #[test]
+    fn test_bad_input() {
+        for content in [
+            "",
+            ".....\n.S-7",
+            ".S-7.\n.|.|.\n.L-J.\n..S..",
+            ".S-X.",
+            "S..\n...",
+            "...\n...",
+        ] {
+            assert!(validate(content).is_err(), "{content:?}");
+        }
+    }
+}
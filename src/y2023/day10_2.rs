@@ -0,0 +1,220 @@
+use crate::{
+    geometry::{Dir, Pos},
+    polygon::Polygon,
+    Answer, Error, Grid,
+};
+
+/// Directions a pipe connects to.
+fn pipe_dirs(pipe: u8) -> &'static [Dir] {
+    match pipe {
+        b'|' => &[Dir::Up, Dir::Down],
+        b'-' => &[Dir::Left, Dir::Right],
+        b'F' => &[Dir::Down, Dir::Right],
+        b'7' => &[Dir::Down, Dir::Left],
+        b'L' => &[Dir::Up, Dir::Right],
+        b'J' => &[Dir::Up, Dir::Left],
+        _ => &[],
+    }
+}
+
+fn parse(content: &str) -> Result<(Grid<u8>, Pos), Error> {
+    let mut pipes = Grid::parse_bytes(content.as_bytes(), |c| {
+        b"|-LJ7F.S".contains(&c).then_some(c)
+    })?;
+    if pipes.iter().filter(|(_, &c)| c == b'S').count() != 1 {
+        return Err(Error::parse("there must be exactly one S"));
+    }
+    let start = pipes.find(&b'S').unwrap();
+    // replace S with the pipe that connects to both of its connected neighbours
+    let connected = Dir::ALL
+        .into_iter()
+        .filter(|&dir| {
+            pipes
+                .get(start.step(dir))
+                .is_some_and(|&next| pipe_dirs(next).contains(&dir.opposite()))
+        })
+        .collect::<Vec<_>>();
+    let Some(pipe) = b"|-F7LJ"
+        .iter()
+        .copied()
+        .find(|&pipe| pipe_dirs(pipe).iter().all(|dir| connected.contains(dir)))
+    else {
+        return Err(Error::parse(format!(
+            "S at {start:?} does not connect to two pipes"
+        )));
+    };
+    pipes[start] = pipe;
+    return Ok((pipes, start));
+}
+
+/// Parse without solving, for the fuzz targets.
+pub fn validate(content: &str) -> Result<(), Error> {
+    return parse(content).map(|_| ());
+}
+
+/// Tiles of the loop through S, in walking order.
+fn trace_main_loop(pipes: &Grid<u8>, starting_coord: Pos) -> Result<Vec<Pos>, Error> {
+    let mut main_loop = vec![starting_coord];
+    let mut dir = pipe_dirs(pipes[starting_coord])[0];
+    let mut coord = starting_coord.step(dir);
+    while coord != starting_coord {
+        // the next pipe has to connect back to where we came from
+        let came_from = dir.opposite();
+        let Some(&pipe) = pipes
+            .get(coord)
+            .filter(|&&pipe| pipe_dirs(pipe).contains(&came_from))
+        else {
+            return Err(Error::parse(format!(
+                "the loop through S breaks at {coord:?}"
+            )));
+        };
+        main_loop.push(coord);
+        dir = *pipe_dirs(pipe)
+            .iter()
+            .find(|&&next| next != came_from)
+            .unwrap();
+        coord = coord.step(dir);
+    }
+    return Ok(main_loop);
+}
+
+fn solve(content: &str) -> Result<i32, Error> {
+    let (pipes, starting_coord) = parse(content)?;
+    let main_loop = trace_main_loop(&pipes, starting_coord)?;
+    // the enclosed tiles are the lattice points strictly inside the loop
+    let area_inside = Polygon::from_positions(main_loop).interior_points();
+    return Ok(area_inside as i32);
+}
+
+pub fn run(content: &str) -> Result<Answer, Error> {
+    let result = solve(content)?;
+    return Ok(result.into());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, solve, trace_main_loop, validate, Grid, Polygon};
+
+    #[test]
+    fn test_main_loop() {
+        let content = String::from(
+            ".S7..
+.LJ..
+.F--7
+.L--J",
+        );
+        let correct = String::from(
+            ".F7..
+.LJ..
+.....
+.....",
+        );
+        let correct = Grid::parse_bytes(correct.as_bytes(), Some).unwrap();
+        let (pipes, starting_coord) = parse(&content).unwrap();
+        let mut result = Grid::new(pipes.h(), pipes.w(), b'.');
+        for pos in trace_main_loop(&pipes, starting_coord).unwrap() {
+            result[pos] = pipes[pos];
+        }
+        assert_eq!(result, correct);
+    }
+
+    #[test]
+    fn test1() {
+        let content = String::from(
+            "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........",
+        );
+        let result = solve(&content).unwrap();
+        assert_eq!(result, 4);
+    }
+
+    #[test]
+    fn test2() {
+        let content = String::from(
+            ".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...",
+        );
+        let result = solve(&content).unwrap();
+        assert_eq!(result, 8);
+    }
+
+    #[test]
+    fn test3() {
+        let content = String::from(
+            "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L",
+        );
+        let result = solve(&content).unwrap();
+        assert_eq!(result, 10);
+    }
+
+    #[test]
+    fn test_point_in_polygon() {
+        // counting tiles one by one agrees with pick's theorem
+        let content = ".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+        let (pipes, starting_coord) = parse(content).unwrap();
+        let main_loop = Polygon::from_positions(trace_main_loop(&pipes, starting_coord).unwrap());
+        let inside = pipes
+            .bounds()
+            .positions()
+            .filter(|&pos| main_loop.contains(pos));
+        assert_eq!(inside.count(), 8);
+    }
+
+    #[test]
+    fn test_broken_loop() {
+        assert!(solve("S-7\n|.|\nL-.").is_err());
+    }
+
+    #[test]
+    fn test_start_in_column_1() {
+        let content = "FS7\n|.|\nL-J";
+        assert_eq!(solve(content).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_bad_input() {
+        for content in [
+            "",
+            ".....\n.S-7",
+            ".S-7.\n.|.|.\n.L-J.\n..S..",
+            ".S-X.",
+            "S..\n...",
+            "...\n...",
+        ] {
+            assert!(validate(content).is_err(), "{content:?}");
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{dump, geometry::Pos, Answer, Error, Grid};
+
+fn parse(content: &str) -> Result<Vec<Pos>, Error> {
+    let space = Grid::parse_bytes(content.as_bytes(), |b| b"#.".contains(&b).then_some(b))?;
+    // find where the hole is in both x and y
+    let (h, w) = (space.h(), space.w());
+    let mut starless_y = vec![0; h];
+    let mut starless_x = vec![0; w];
+    for (i, row) in space.rows().enumerate() {
+        if row.iter().all(|&x| x == b'.') {
+            starless_y[i] = 1;
+        }
+    }
+    for j in 0..w {
+        let mut galaxy_found = false;
+        for i in 0..h {
+            if space[(i, j)] == b'#' {
+                galaxy_found = true;
+                break;
+            }
+        }
+        if !galaxy_found {
+            starless_x[j] = 1;
+        }
+    }
+    // calculate the coords but added with cumulative sum of holes
+    let starless_y_cumsum = starless_y
+        .iter()
+        .scan(0, |cumsum, x| {
+            *cumsum += x;
+            Some(*cumsum)
+        })
+        .collect::<Vec<_>>();
+    let starless_x_cumsum = starless_x
+        .iter()
+        .scan(0, |cumsum, x| {
+            *cumsum += x;
+            Some(*cumsum)
+        })
+        .collect::<Vec<_>>();
+    // return the galaxies but with the cumulative sum added
+    let mut galaxies = vec![];
+    for (i, row) in space.rows().enumerate() {
+        for (j, &c) in row.iter().enumerate() {
+            if c == b'#' {
+                galaxies.push(Pos::new(
+                    i as i64 + starless_y_cumsum[i],
+                    j as i64 + starless_x_cumsum[j],
+                ))
+            }
+        }
+    }
+    dump!(Debug, "hole_y   {starless_y:?}");
+    dump!(Debug, "cumsum_y {starless_y_cumsum:?}");
+    dump!(Debug, "hole_x   {starless_x:?}");
+    dump!(Debug, "cumsum_x {starless_x_cumsum:?}");
+    return Ok(galaxies);
+}
+
+pub fn validate(content: &str) -> Result<(), Error> {
+    return parse(content).map(|_| ());
+}
+
+fn shortest_paths(galaxies: &[Pos]) -> i64 {
+    let mut dist = 0;
+    let n = galaxies.len();
+    for i in 0..n {
+        for j in i + 1..n {
+            dist += galaxies[i].manhattan(galaxies[j]);
+        }
+    }
+    return dist;
+}
+
+fn solve(content: &str) -> Result<i64, Error> {
+    let galaxies = parse(content)?;
+    let dist = shortest_paths(&galaxies);
+    return Ok(dist);
+}
+
+pub fn run(content: &str) -> Result<Answer, Error> {
+    let result = solve(content)?;
+    return Ok(result.into());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::solve;
+
+    #[test]
+    fn test() {
+        let content = String::from(
+            "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....",
+        );
+        let result = solve(&content).unwrap();
+        assert_eq!(result, 374);
+    }
+}
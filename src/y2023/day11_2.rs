@@ -1,35 +1,20 @@
-use std::fs;
+use crate::{dump, geometry::Pos, params::Param, Answer, Error, Grid, Params};
 
-#[derive(Default, Debug)]
-struct Coord {
-    y: i64,
-    x: i64,
-}
-impl Coord {
-    fn manhattan(&self, other: &Coord) -> i64 {
-        (self.y - other.y).abs() + (self.x - other.x).abs()
-    }
-}
-
-fn parse(content: &String, expansion_multiplier: i64) -> Vec<Coord> {
-    // parse to char first
-    let space = content
-        .lines()
-        .map(|row| row.chars().collect())
-        .collect::<Vec<Vec<char>>>();
+fn parse(content: &str, expansion_multiplier: i64) -> Result<Vec<Pos>, Error> {
+    let space = Grid::parse_bytes(content.as_bytes(), |b| b"#.".contains(&b).then_some(b))?;
     // find where the hole is in both x and y
-    let (h, w) = (space.len(), space[0].len());
+    let (h, w) = (space.h(), space.w());
     let mut starless_y = vec![0; h];
     let mut starless_x = vec![0; w];
-    for (i, row) in space.iter().enumerate() {
-        if row.iter().all(|&x| x == '.') {
+    for (i, row) in space.rows().enumerate() {
+        if row.iter().all(|&x| x == b'.') {
             starless_y[i] = 1;
         }
     }
     for j in 0..w {
         let mut galaxy_found = false;
         for i in 0..h {
-            if space[i][j] == '#' {
+            if space[(i, j)] == b'#' {
                 galaxy_found = true;
                 break;
             }
@@ -55,50 +40,59 @@ fn parse(content: &String, expansion_multiplier: i64) -> Vec<Coord> {
         .collect::<Vec<_>>();
     // return the galaxies but with the cumulative sum added
     let mut galaxies = vec![];
-    for (i, row) in space.iter().enumerate() {
+    for (i, row) in space.rows().enumerate() {
         for (j, &c) in row.iter().enumerate() {
-            if c == '#' {
+            if c == b'#' {
                 // -1 is for taking into account that we are still counting the original empty space
-                galaxies.push(Coord {
-                    y: i as i64 + ((expansion_multiplier - 1) * starless_y_cumsum[i]),
-                    x: j as i64 + ((expansion_multiplier - 1) * starless_x_cumsum[j]),
-                })
+                galaxies.push(Pos::new(
+                    i as i64 + ((expansion_multiplier - 1) * starless_y_cumsum[i]),
+                    j as i64 + ((expansion_multiplier - 1) * starless_x_cumsum[j]),
+                ))
             }
         }
     }
-    // println!("hole_y   {:?}", starless_y);
-    // println!("cumsum_y {:?}", starless_y_cumsum);
-    // println!("hole_x   {:?}", starless_x);
-    // println!("cumsum_x {:?}", starless_x_cumsum);
-    // println!();
-    return galaxies;
+    dump!(Debug, "hole_y   {starless_y:?}");
+    dump!(Debug, "cumsum_y {starless_y_cumsum:?}");
+    dump!(Debug, "hole_x   {starless_x:?}");
+    dump!(Debug, "cumsum_x {starless_x_cumsum:?}");
+    return Ok(galaxies);
 }
 
-fn shortest_paths(galaxies: &Vec<Coord>) -> i64 {
+pub fn validate(content: &str) -> Result<(), Error> {
+    return parse(content, 1).map(|_| ());
+}
+
+fn shortest_paths(galaxies: &[Pos]) -> i64 {
     let mut dist = 0;
     let n = galaxies.len();
     for i in 0..n {
         for j in i + 1..n {
-            dist += galaxies[i].manhattan(&galaxies[j]);
+            dist += galaxies[i].manhattan(galaxies[j]);
         }
     }
     return dist;
 }
 
-fn solve(content: &String, expansion_multiplier: i64) -> i64 {
-    let galaxies = parse(content, expansion_multiplier);
-    // for g in galaxies.iter() {
-    //     println!("{:?}", g);
-    // }
+fn solve(content: &str, expansion_multiplier: i64) -> Result<i64, Error> {
+    let galaxies = parse(content, expansion_multiplier)?;
+    for g in galaxies.iter() {
+        dump!(Trace, "{g:?}");
+    }
     let dist = shortest_paths(&galaxies);
-    return dist;
+    return Ok(dist);
 }
 
-fn main() {
-    // WOW i did not know this, turns out you can pass &str to a function that accepts Path because of AsRef<Path>
-    let content = fs::read_to_string("inputs/day11.txt").expect("input for day 11 is missing");
-    let result = solve(&content, 1000000);
-    println!("day 11 part 2: {}", result);
+pub const PARAMS: &[Param] = &[Param {
+    name: "expansion",
+    default: 1000000,
+    min: 1,
+    max: i64::MAX,
+    help: "how many times larger an empty row or column becomes",
+}];
+
+pub fn run(content: &str, params: &Params) -> Result<Answer, Error> {
+    let result = solve(content, params.get("expansion")?)?;
+    return Ok(result.into());
 }
 
 #[cfg(test)]
@@ -124,8 +118,8 @@ mod tests {
         // multiplier = 1 means there is no change
         let multipliers = vec![2, 10, 100];
         let answers = vec![374, 1030, 8410];
-        for (mult, ans) in multipliers.into_iter().zip(answers.into_iter()) {
-            let result = solve(&content, mult);
+        for (mult, ans) in multipliers.into_iter().zip(answers) {
+            let result = solve(&content, mult).unwrap();
             assert_eq!(result, ans);
         }
     }
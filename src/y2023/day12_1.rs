@@ -1,7 +1,7 @@
-use std::fs;
-
 use itertools::Itertools;
 
+use crate::{dump, registry::Algo, Answer, Error};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Block {
     Opr,
@@ -9,7 +9,7 @@ enum Block {
     Unk,
 }
 
-fn parse(content: &String) -> Vec<(Vec<Block>, Vec<i32>)> {
+fn parse(content: &str) -> Vec<(Vec<Block>, Vec<i32>)> {
     let mut parsed = vec![];
     for line in content.lines() {
         let (field, truth) = line.split_once(" ").unwrap();
@@ -73,44 +73,44 @@ fn find_combinations(
             continue;
         }
         // at this point, you can start "painting"
-        for j in i..i + curr_truth {
-            buffer[j] = Block::Dmg;
-        }
+        buffer[i..i + curr_truth].fill(Block::Dmg);
         // recursive backtracking, the new starting index is one +1 from the last painted block, +1 for gap
         total_so_far += find_combinations(blocks, truths, buffer, level + 1, i + curr_truth + 1);
         // restore to the original "paint"
-        for j in i..i + curr_truth {
-            buffer[j] = blocks[j];
-        }
+        buffer[i..i + curr_truth].copy_from_slice(&blocks[i..i + curr_truth]);
         // move to the next spot
         i += 1;
     }
     return total_so_far;
 }
 
-fn solve(content: &String) -> i32 {
+pub(crate) fn solve(content: &str) -> i32 {
     let parsed = parse(content);
     let mut total = 0;
     for (blocks, truths) in parsed.iter() {
-        // println!("{:?}", blocks);
-        // println!("{:?}", truths);
-        let mut buffer = blocks.iter().copied().collect::<Vec<_>>();
+        let mut buffer = blocks.to_vec();
         let temp = find_combinations(blocks, truths, &mut buffer, 0, 0);
-        // println!("{}", temp);
+        dump!(Trace, "{blocks:?} {truths:?} => {temp}");
         total += temp;
     }
     return total;
 }
 
-fn main() {
-    let content = fs::read_to_string("inputs/day12.txt").expect("input for day 12 is missing");
-    let result = solve(&content);
-    println!("day 12 part 1: {}", result);
+pub fn run(content: &str) -> Result<Answer, Error> {
+    let result = solve(content);
+    return Ok(result.into());
 }
 
+pub const ALGOS: &[Algo] = &[Algo {
+    name: "dp",
+    help: "the memoized search of part 2, without unfolding",
+    run: |content, _, _, _| Ok(crate::y2023::day12_2::solve(content, 1)?.into()),
+}];
+
 #[cfg(test)]
 mod tests {
     use super::solve;
+    use crate::{registry, Answer, Params};
 
     #[test]
     fn test() {
@@ -124,5 +124,9 @@ mod tests {
         );
         let result = solve(&content);
         assert_eq!(result, 21);
+        assert_eq!(
+            registry::assert_algos_agree(2023, 12, 1, &content, &Params::new()),
+            Answer::Int(21)
+        );
     }
 }
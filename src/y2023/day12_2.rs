@@ -0,0 +1,237 @@
+// complete rewrite of part 1, now using proper top down DP
+// huge thanks to: https://www.reddit.com/r/adventofcode/comments/18hbbxe/2023_day_12python_stepbystep_tutorial_with_bonus/
+// TODO my question: why is this (on debug build) slower than python implementation?
+
+use std::collections::HashMap;
+
+use crate::{params::Param, registry::Algo, Answer, Error, Params};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Block {
+    Opr,
+    Dmg,
+    Unk,
+}
+
+/// Index of the next block and of the next group to lay, what is left of a question.
+type State = (usize, usize);
+
+struct Question {
+    data: Vec<Block>,
+    groups: Vec<usize>,
+}
+impl Question {
+    fn skip(&self, (i, g): State) -> Option<State> {
+        // if we find '#', we must lay, because if we skip '#' then it will be a stray '#'
+        if self.data[i] == Block::Dmg {
+            return None;
+        }
+        return Some((i + 1, g));
+    }
+
+    fn lay(&self, (i, g): State) -> Option<State> {
+        // lay means we start planting the groups
+        if self.data[i] == Block::Opr || g == self.groups.len() {
+            // we cannot lay on '.' or if there is no more group
+            return None;
+        }
+        let end = i + self.groups[g];
+        if self.data.len() < end {
+            // we cannot lay if not enough space
+            return None;
+        }
+        if self.data[i..end].contains(&Block::Opr) {
+            // can't lay because '.' underneath
+            return None;
+        }
+        if self.data.len() == end {
+            // special case at the very end
+            return Some((end, g + 1));
+        }
+        // because this is not the very end, the +1 after that must NOT be #
+        // because if . we can fullfill current group, if ? we assume .
+        if self.data[end] == Block::Dmg {
+            return None;
+        }
+        return Some((end + 1, g + 1));
+    }
+
+    /// Combinations of the finished states, or of those already counted.
+    fn known(&self, memo: &HashMap<State, i64>, (i, g): State) -> Option<i64> {
+        if i == self.data.len() {
+            // valid finish state if every group got laid, prune otherwise
+            return Some(i64::from(g == self.groups.len()));
+        }
+        return memo.get(&(i, g)).copied();
+    }
+}
+
+fn parse(content: &str, copies: usize) -> Vec<Question> {
+    let mut questions = vec![];
+    for line in content.lines() {
+        let (raw_data, raw_groups) = line.split_once(" ").unwrap();
+        let raw_data = raw_data
+            .chars()
+            .map(|c| match c {
+                '.' => Block::Opr,
+                '#' => Block::Dmg,
+                '?' => Block::Unk,
+                _ => unreachable!("impossible during parsing"),
+            })
+            .collect::<Vec<_>>();
+        let raw_groups = raw_groups
+            .split(",")
+            .map(|num| num.parse::<usize>().unwrap())
+            .collect::<Vec<_>>();
+        // make copies
+        let mut data = raw_data.clone();
+        for _ in 0..copies - 1 {
+            data.push(Block::Unk);
+            data.extend(raw_data.clone());
+        }
+        let groups = vec![raw_groups; copies].concat();
+        questions.push(Question { data, groups });
+    }
+    return questions;
+}
+
+/// `None` once the count no longer fits an `i64`, which takes a lot of copies.
+fn calculate_combinations(question: &Question) -> Option<i64> {
+    // top down, with the states still to count on an explicit stack: a recursion as deep as
+    // the unfolded records overflows with many copies
+    let mut memo = HashMap::new();
+    let mut stack = vec![(0, 0)];
+    while let Some(&state) = stack.last() {
+        if question.known(&memo, state).is_some() {
+            stack.pop();
+            continue;
+        }
+        // '.' can only be skipped, '?' can be either
+        let next = [question.skip(state), question.lay(state)];
+        let missing = next
+            .iter()
+            .flatten()
+            .filter(|&&next| question.known(&memo, next).is_none())
+            .copied()
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            stack.extend(missing);
+            continue;
+        }
+        let mut total_so_far: i64 = 0;
+        for next in next.iter().flatten() {
+            total_so_far = total_so_far.checked_add(question.known(&memo, *next).unwrap())?;
+        }
+        memo.insert(state, total_so_far);
+        stack.pop();
+    }
+    return question.known(&memo, (0, 0));
+}
+
+pub(crate) fn solve(content: &str, copies: usize) -> Result<i64, Error> {
+    let too_many = || {
+        Error::Param(format!(
+            "too many arrangements for an i64 with {copies} copies"
+        ))
+    };
+    let questions = parse(content, copies);
+    let mut total: i64 = 0;
+    for question in &questions {
+        let count = calculate_combinations(question).ok_or_else(too_many)?;
+        total = total.checked_add(count).ok_or_else(too_many)?;
+    }
+    return Ok(total);
+}
+
+pub const PARAMS: &[Param] = &[Param {
+    name: "copies",
+    default: 5,
+    min: 1,
+    max: i64::MAX,
+    help: "how many times each record is unfolded",
+}];
+
+pub fn run(content: &str, params: &Params) -> Result<Answer, Error> {
+    let result = solve(content, params.get("copies")?)?;
+    return Ok(result.into());
+}
+
+/// The records as text, each one unfolded `copies` times.
+fn unfold(content: &str, copies: usize) -> String {
+    let mut unfolded = String::new();
+    for line in content.lines() {
+        let (data, groups) = line.split_once(" ").unwrap();
+        unfolded += &vec![data; copies].join("?");
+        unfolded += " ";
+        unfolded += &vec![groups; copies].join(",");
+        unfolded += "\n";
+    }
+    return unfolded;
+}
+
+fn brute_force(content: &str, params: &Params) -> Result<Answer, Error> {
+    let unfolded = unfold(content, params.get("copies")?);
+    let result = crate::y2023::day12_1::solve(&unfolded);
+    return Ok(result.into());
+}
+
+pub const ALGOS: &[Algo] = &[Algo {
+    name: "brute-force",
+    help: "the backtracking of part 1 on the unfolded records, only feasible with few copies",
+    run: |content, params, _, _| brute_force(content, params),
+}];
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn test_easy() {
+        let content = String::from(
+            "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1",
+        );
+        let result = solve(&content, 1).unwrap();
+        assert_eq!(result, 21);
+        assert_eq!(unfold(".# 1", 3), ".#?.#?.# 1,1,1\n");
+        // the brute force does not get far with the default 5 copies
+        let mut overrides = Params::new();
+        overrides.set("copies", 2);
+        assert_eq!(
+            registry::assert_algos_agree(2023, 12, 2, &content, &overrides),
+            Answer::Int(solve(&content, 2).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_hard() {
+        let content = String::from(
+            "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1",
+        );
+        let result = solve(&content, 5).unwrap();
+        assert_eq!(result, 525152);
+    }
+
+    #[test]
+    fn test_many_copies() {
+        // `#?#?#...`, the only way is to put each group on its own spring
+        assert_eq!(solve("# 1", 100_000), Ok(1));
+    }
+
+    #[test]
+    fn test_big_count() {
+        // the last line of the example grows 15 times over with every copy
+        let line = "?###???????? 3,2,1";
+        assert_eq!(solve(line, 16), Ok(4378938903808593750));
+        assert!(matches!(solve(line, 17), Err(Error::Param(_))));
+    }
+}
@@ -0,0 +1,163 @@
+use std::cmp;
+
+use crate::{
+    certificate::{self, Certificate, Mirror},
+    dump, Answer, Error, Grid,
+};
+
+struct Cave {
+    data: Grid<char>,
+}
+impl Cave {
+    fn dump(&self) {
+        dump!(Trace, "cave\n{}", self.data.render(|&c| c));
+    }
+    fn find_smudged_horizontal_mirror(&self) -> Option<usize> {
+        find_smudged_mirror(&self.data)
+    }
+    fn find_smudged_vertical_mirror(&self) -> Option<usize> {
+        // a vertical mirror is a horizontal one once columns become rows
+        find_smudged_mirror(&self.data.transposed())
+    }
+}
+
+/// Rows above the horizontal mirror line that is off by exactly one cell.
+fn find_smudged_mirror(data: &Grid<char>) -> Option<usize> {
+    let nrows = data.h();
+    for mirror in 0..nrows - 1 {
+        let mut diff = 0;
+        let comparisons = cmp::min(mirror + 1, nrows - mirror - 1);
+        for i in 0..comparisons {
+            let (above, below) = (data.row(mirror - i), data.row(mirror + i + 1));
+            diff += above.iter().zip(below).filter(|(a, b)| a != b).count();
+        }
+        if diff == 1 {
+            return Some(mirror + 1); // +1 to convert index to line count
+        }
+    }
+    None
+}
+
+fn parse(content: &str) -> Result<Vec<Cave>, Error> {
+    let mut caves = vec![];
+    for block in content.split("\n\n") {
+        let data = Grid::parse(block, |ch| matches!(ch, '#' | '.').then_some(ch))?;
+        caves.push(Cave { data });
+    }
+    return Ok(caves);
+}
+
+/// The mirror line of every cave.
+fn find_mirrors(content: &str) -> Result<Vec<Mirror>, Error> {
+    let caves = parse(content)?;
+    let mut mirrors = vec![];
+    for cave in caves {
+        cave.dump();
+        let horizontal = cave.find_smudged_horizontal_mirror();
+        let vertical = cave.find_smudged_vertical_mirror();
+        match (horizontal, vertical) {
+            (Some(h), None) => mirrors.push(Mirror::Rows(h)),
+            (None, Some(v)) => mirrors.push(Mirror::Columns(v)),
+            _ => panic!("mirror must either vertical or horizontal but not both"),
+        }
+    }
+    return Ok(mirrors);
+}
+
+fn summarize(mirrors: &[Mirror]) -> i32 {
+    let mut total = 0;
+    for mirror in mirrors {
+        match mirror {
+            Mirror::Rows(h) => total += h * 100,
+            Mirror::Columns(v) => total += v,
+        }
+    }
+    return total as i32;
+}
+
+fn solve(content: &str) -> Result<i32, Error> {
+    return Ok(summarize(&find_mirrors(content)?));
+}
+
+pub fn run(content: &str) -> Result<Answer, Error> {
+    let result = solve(content)?;
+    return Ok(result.into());
+}
+
+pub fn certify(content: &str) -> Result<(Answer, Certificate), Error> {
+    let mirrors = find_mirrors(content)?;
+    return Ok((summarize(&mirrors).into(), Certificate::Mirrors(mirrors)));
+}
+
+/// Cells that differ from their reflection across the line below `rows` rows.
+fn differences(data: &Grid<char>, rows: usize) -> usize {
+    let pairs = cmp::min(rows, data.h() - rows);
+    return (0..pairs)
+        .map(|i| (data.row(rows - 1 - i), data.row(rows + i)))
+        .map(|(above, below)| above.iter().zip(below).filter(|(a, b)| a != b).count())
+        .sum();
+}
+
+/// Every cave reflects across its line with exactly one smudge, and the lines add up to the answer.
+pub fn check(content: &str, answer: &Answer, certificate: &Certificate) -> Result<(), Error> {
+    let caves = parse(content)?;
+    let Certificate::Mirrors(mirrors) = certificate else {
+        return Err(Error::rejected("not mirror lines"));
+    };
+    if mirrors.len() != caves.len() {
+        let msg = format!("{} mirrors for {} caves", mirrors.len(), caves.len());
+        return Err(Error::rejected(msg));
+    }
+    for (i, (cave, &mirror)) in caves.iter().zip(mirrors).enumerate() {
+        let (data, line) = match mirror {
+            Mirror::Rows(n) => (cave.data.clone(), n),
+            Mirror::Columns(n) => (cave.data.transposed(), n),
+        };
+        // a line on the edge reflects nothing
+        if line == 0 || line >= data.h() || differences(&data, line) != 1 {
+            let msg = format!("cave {} does not reflect {:?}", i + 1, mirror);
+            return Err(Error::rejected(msg));
+        }
+    }
+    return certificate::claims(answer, summarize(mirrors));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let content = String::from(
+            "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#",
+        );
+        let result = solve(&content).unwrap();
+        assert_eq!(result, 400);
+
+        let (answer, certificate) = certify(&content).unwrap();
+        assert_eq!(
+            certificate,
+            Certificate::Mirrors(vec![Mirror::Rows(3), Mirror::Rows(1)])
+        );
+        assert_eq!(check(&content, &answer, &certificate), Ok(()));
+        // the mirrors of part 1, they have no smudge
+        let wrong = Certificate::Mirrors(vec![Mirror::Columns(5), Mirror::Rows(4)]);
+        assert!(check(&content, &answer, &wrong).is_err());
+        let missing = Certificate::Mirrors(vec![Mirror::Columns(5)]);
+        assert!(check(&content, &answer, &missing).is_err());
+    }
+}
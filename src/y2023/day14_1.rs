@@ -1,4 +1,4 @@
-use std::fs;
+use crate::{Answer, Error, Facts, Grid};
 
 #[derive(Debug, PartialEq, Eq)]
 enum Rock {
@@ -7,24 +7,21 @@ enum Rock {
     Air,
 }
 
-fn parse(content: &String) -> Vec<Vec<Rock>> {
-    content
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|rock| match rock {
-                    'O' => Rock::Round,
-                    '#' => Rock::Square,
-                    '.' => Rock::Air,
-                    invalid => unreachable!("bad input {invalid}"),
-                })
-                .collect()
-        })
-        .collect()
+fn parse(content: &str) -> Result<Grid<Rock>, Error> {
+    return Grid::parse_bytes(content.as_bytes(), |rock| match rock {
+        b'O' => Some(Rock::Round),
+        b'#' => Some(Rock::Square),
+        b'.' => Some(Rock::Air),
+        _ => None,
+    });
 }
 
-fn tilt_north(map: &Vec<Vec<Rock>>) -> i32 {
-    let (h, w) = (map.len(), map[0].len());
+pub fn validate(content: &str) -> Result<(), Error> {
+    return parse(content).map(|_| ());
+}
+
+fn tilt_north(map: &Grid<Rock>) -> i32 {
+    let (h, w) = (map.h(), map.w());
     let mut total = 0;
     for j in 0..w {
         // count round rocks in the segment and use some cool shit math
@@ -36,7 +33,7 @@ fn tilt_north(map: &Vec<Vec<Rock>>) -> i32 {
                 start_pos = i;
                 is_after_square_rock = false;
             }
-            match map[i][j] {
+            match map[(i, j)] {
                 Rock::Air => {
                     // find air, do nothing
                 }
@@ -70,15 +67,25 @@ fn tilt_north(map: &Vec<Vec<Rock>>) -> i32 {
     total as i32
 }
 
-fn solve(content: &String) -> i32 {
-    let map = parse(content);
-    tilt_north(&map)
+fn solve(content: &str) -> Result<i32, Error> {
+    let map = parse(content)?;
+    return Ok(tilt_north(&map));
+}
+
+pub fn run(content: &str) -> Result<Answer, Error> {
+    let result = solve(content)?;
+    return Ok(result.into());
 }
 
-fn main() {
-    let content = fs::read_to_string("inputs/day14.txt").expect("input for day 14 is missing");
-    let result = solve(&content);
-    println!("day 14 part 1: {}", result);
+pub fn inspect(content: &str) -> Result<Facts, Error> {
+    let map = parse(content)?;
+    let count = |rock| map.iter().filter(|(_, r)| **r == rock).count();
+    let mut facts = Facts::new();
+    facts.note("rows", map.h());
+    facts.note("columns", map.w());
+    facts.note("round rocks", count(Rock::Round));
+    facts.note("square rocks", count(Rock::Square));
+    return Ok(facts);
 }
 
 #[cfg(test)]
@@ -99,7 +106,7 @@ O.#..O.#.#
 #....###..
 #OO..#....",
         );
-        let result = solve(&content);
+        let result = solve(&content).unwrap();
         assert_eq!(result, 136);
     }
 }
@@ -0,0 +1,219 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+
+use crate::{dump, dump::Level, params::Param, Answer, Error, Facts, Grid, Params};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Rock {
+    Round,
+    Square,
+    Air,
+}
+
+#[derive(Hash)]
+struct Engine {
+    map: Grid<Rock>,
+}
+impl Engine {
+    fn north(&mut self) {
+        let (h, w) = (self.map.h(), self.map.w());
+        for j in 0..w {
+            let mut start_pos = 0;
+            let mut is_after_square_rock = true;
+            let mut round_rock_count = 0;
+            for i in 0..h {
+                if is_after_square_rock {
+                    start_pos = i;
+                    is_after_square_rock = false;
+                }
+                match self.map[(i, j)] {
+                    Rock::Round => {
+                        self.map[(i, j)] = Rock::Air;
+                        round_rock_count += 1;
+                    }
+                    Rock::Square => {
+                        for k in start_pos..start_pos + round_rock_count {
+                            self.map[(k, j)] = Rock::Round;
+                        }
+                        is_after_square_rock = true;
+                        round_rock_count = 0;
+                    }
+                    _ => {}
+                }
+            }
+            for k in start_pos..start_pos + round_rock_count {
+                self.map[(k, j)] = Rock::Round;
+            }
+        }
+    }
+    fn cycle(&mut self) {
+        // tilting north and turning clockwise brings west, then south, then east to the top,
+        // the fourth turn restores the original orientation
+        for _ in 0..4 {
+            self.north();
+            self.map = self.map.rotated_clockwise();
+        }
+    }
+    fn get_score(&self) -> i32 {
+        let h = self.map.h();
+        self.map
+            .rows()
+            .enumerate()
+            .map(|(i, row)| {
+                row.iter()
+                    .map(|rock| match rock {
+                        Rock::Round => h - i,
+                        _ => 0,
+                    })
+                    .sum::<usize>()
+            })
+            .sum::<usize>() as i32
+    }
+    fn get_hash(&self) -> u64 {
+        let mut state = DefaultHasher::new();
+        self.hash(&mut state);
+        state.finish()
+    }
+    fn dump(&self, when: &str) {
+        let temp = self.map.render(|rock| match rock {
+            Rock::Air => '.',
+            Rock::Round => 'O',
+            Rock::Square => '#',
+        });
+        dump!(Debug, "{when}\n{temp}");
+    }
+}
+
+fn parse(content: &str) -> Result<Engine, Error> {
+    let map = Grid::parse_bytes(content.as_bytes(), |rock| match rock {
+        b'O' => Some(Rock::Round),
+        b'#' => Some(Rock::Square),
+        b'.' => Some(Rock::Air),
+        _ => None,
+    })?;
+    return Ok(Engine { map });
+}
+
+pub fn validate(content: &str) -> Result<(), Error> {
+    return parse(content).map(|_| ());
+}
+
+fn solve(content: &str, cycles: usize, facts: &mut Facts) -> Result<i32, Error> {
+    let mut engine = parse(content)?;
+    let mut hash_collection = HashSet::new();
+    hash_collection.insert(engine.get_hash());
+    // idea:
+    // 1. find duplicates, that means the sequence will be like xxxxxabcdea <- last a triggers duplicate
+    // 2. we want vector starting with hash a, that is abcde, but instead of hash, we store the score
+    // 3. predict the future using how many iterations left and using modulo to get the score
+
+    // step 1
+    let mut scores = vec![];
+    let mut iterations_done = 0;
+    let first_duplicate = loop {
+        if iterations_done == cycles {
+            facts.note("cycles simulated", iterations_done);
+            return Ok(engine.get_score());
+        }
+        engine.cycle();
+        iterations_done += 1;
+        let curr_hash = engine.get_hash();
+        dump!(
+            Trace,
+            "[{iterations_done}] {curr_hash} score {}",
+            engine.get_score()
+        );
+        if hash_collection.contains(&curr_hash) {
+            scores.push(engine.get_score());
+            break curr_hash;
+        }
+        hash_collection.insert(curr_hash);
+    };
+    // step 2
+    let loop_found_at = iterations_done;
+    if dump::enabled(Level::Debug) {
+        engine.dump(&format!("repeats after {loop_found_at} cycles"));
+    }
+    loop {
+        if iterations_done == cycles {
+            facts.note("cycles simulated", iterations_done);
+            return Ok(engine.get_score());
+        }
+        engine.cycle();
+        iterations_done += 1;
+        let curr_hash = engine.get_hash();
+        if curr_hash == first_duplicate {
+            break;
+        }
+        scores.push(engine.get_score());
+    }
+    // step 3
+    let iterations_todo = cycles - iterations_done;
+    facts.note("cycles simulated", iterations_done);
+    facts.note("loop offset", loop_found_at - scores.len());
+    facts.note("loop length", scores.len());
+    facts.note("cycles extrapolated", iterations_todo);
+    facts.note("position in loop", iterations_todo % scores.len());
+    Ok(scores[iterations_todo % scores.len()]) // 88371
+}
+
+pub const PARAMS: &[Param] = &[Param {
+    name: "cycles",
+    default: 1000000000,
+    min: 0,
+    max: i64::MAX,
+    help: "spin cycles to run",
+}];
+
+pub fn run(content: &str, params: &Params, facts: &mut Facts) -> Result<Answer, Error> {
+    let result = solve(content, params.get("cycles")?, facts)?;
+    return Ok(result.into());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let content = String::from(
+            "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....",
+        );
+        let mut facts = Facts::new();
+        let result = solve(&content, 1000000000, &mut facts).unwrap();
+        assert_eq!(result, 64);
+        assert_eq!(facts.get("loop offset"), Some("3"));
+        assert_eq!(facts.get("loop length"), Some("7"));
+    }
+
+    #[test]
+    fn test_few_cycles() {
+        let content = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+        // the shortcut must agree with plain simulation, before and after the loop is found
+        let mut engine = parse(content).unwrap();
+        for cycles in 0..30 {
+            let result = solve(content, cycles, &mut Facts::new()).unwrap();
+            assert_eq!(result, engine.get_score(), "{cycles} cycles");
+            engine.cycle();
+        }
+    }
+}
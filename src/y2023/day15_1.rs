@@ -1,6 +1,6 @@
-use std::fs;
+use crate::{Answer, Error};
 
-fn solve(content: &String) -> i32 {
+fn solve(content: &str) -> i32 {
     let parsed = content
         .split(",")
         .map(str::to_string)
@@ -16,10 +16,9 @@ fn solve(content: &String) -> i32 {
     total_hash
 }
 
-fn main() {
-    let content = fs::read_to_string("inputs/day15.txt").expect("input for day 15 is missing");
-    let result = solve(&content);
-    println!("day 15 part 1: {}", result);
+pub fn run(content: &str) -> Result<Answer, Error> {
+    let result = solve(content);
+    return Ok(result.into());
 }
 
 #[cfg(test)]
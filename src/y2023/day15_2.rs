@@ -1,4 +1,4 @@
-use std::fs;
+use crate::{dump, dump::Level, params::Param, Answer, Error, Params};
 
 enum Command {
     Add { label: String, focal_length: usize },
@@ -11,9 +11,7 @@ struct Lens {
     focal_length: usize,
 }
 
-const N: usize = 256;
-
-fn parse(content: &String) -> Vec<Command> {
+fn parse(content: &str) -> Vec<Command> {
     content
         .split(",")
         .map(|p| match p.contains("=") {
@@ -34,24 +32,24 @@ fn parse(content: &String) -> Vec<Command> {
         .collect()
 }
 
-fn label_hash(s: &str) -> usize {
+fn label_hash(s: &str, n_boxes: usize) -> usize {
     let mut curr_hash = 0;
     for c in s.chars() {
-        curr_hash = (curr_hash + c as usize) * 17 % N;
+        curr_hash = (curr_hash + c as usize) * 17 % n_boxes;
     }
     curr_hash
 }
 
-fn solve(content: &String) -> i32 {
+fn solve(content: &str, n_boxes: usize) -> i64 {
     let commands = parse(content);
-    let mut boxes: Vec<Vec<Lens>> = vec![vec![]; N];
+    let mut boxes: Vec<Vec<Lens>> = vec![vec![]; n_boxes];
     for command in commands {
         match command {
             Command::Add {
                 label,
                 focal_length,
             } => {
-                let index = label_hash(&label);
+                let index = label_hash(&label, n_boxes);
                 let new_lens = Lens {
                     label: label.clone(),
                     focal_length,
@@ -63,35 +61,42 @@ fn solve(content: &String) -> i32 {
                 }
             }
             Command::Delete { label } => {
-                let index = label_hash(&label);
+                let index = label_hash(&label, n_boxes);
                 if let Some(lens_idx) = boxes[index].iter().position(|lens| lens.label == label) {
                     boxes[index].remove(lens_idx);
                 }
             }
         }
     }
-    // debug
-    // for (box_id, lenses) in boxes.iter().enumerate() {
-    //     if lenses.is_empty() {
-    //         continue;
-    //     }
-    //     println!("[{box_id}] {lenses:?}");
-    // }
+    if dump::enabled(Level::Debug) {
+        for (box_id, lenses) in boxes.iter().enumerate() {
+            if !lenses.is_empty() {
+                dump!(Debug, "[{box_id}] {lenses:?}");
+            }
+        }
+    }
     let mut total = 0;
     for (box_id, lenses) in boxes.iter().enumerate() {
         total += lenses
             .iter()
             .enumerate()
-            .map(|(lens_id, lens)| (box_id + 1) * (lens_id + 1) * lens.focal_length)
-            .sum::<usize>();
+            .map(|(lens_id, lens)| ((box_id + 1) * (lens_id + 1) * lens.focal_length) as i64)
+            .sum::<i64>();
     }
-    total as i32
+    total
 }
 
-fn main() {
-    let content = fs::read_to_string("inputs/day15.txt").expect("input for day 15 is missing");
-    let result = solve(&content);
-    println!("day 15 part 2: {}", result);
+pub const PARAMS: &[Param] = &[Param {
+    name: "boxes",
+    default: 256,
+    min: 1,
+    max: 1 << 20,
+    help: "boxes in the line, the hash is taken modulo this",
+}];
+
+pub fn run(content: &str, params: &Params) -> Result<Answer, Error> {
+    let result = solve(content, params.get("boxes")?);
+    return Ok(result.into());
 }
 
 #[cfg(test)]
@@ -101,7 +106,32 @@ mod tests {
     #[test]
     fn test() {
         let content = String::from("rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7");
-        let result = solve(&content);
+        let result = solve(&content, 256);
         assert_eq!(result, 145);
     }
+
+    #[test]
+    fn test_one_box() {
+        // every lens ends up in box 1 in the order rn, cm, ot, ab, pc
+        let content = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+        assert_eq!(solve(content, 1), 1 + 2 * 2 + 3 * 7 + 4 * 5 + 5 * 6);
+    }
+
+    #[test]
+    fn test_too_many_boxes() {
+        let mut overrides = Params::new();
+        overrides.set("boxes", 1 << 20);
+        assert!(Params::resolve(PARAMS, &overrides).is_ok());
+        overrides.set("boxes", i64::MAX);
+        assert!(matches!(
+            Params::resolve(PARAMS, &overrides),
+            Err(Error::Param(_))
+        ));
+    }
+
+    #[test]
+    fn test_big_total() {
+        // rn goes to box 0, past what an i32 holds
+        assert_eq!(solve("rn=3000000000", 256), 3000000000);
+    }
 }
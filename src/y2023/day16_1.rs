@@ -0,0 +1,145 @@
+use std::collections::HashSet;
+
+use crate::{
+    dump,
+    geometry::{Dir, Pos},
+    Answer, Error, Facts, Grid,
+};
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+enum Tile {
+    LeftRight,
+    UpDown,
+    Positive,
+    Negative,
+    Empty,
+}
+
+fn parse(content: &str) -> Result<Grid<Tile>, Error> {
+    return Grid::parse_bytes(content.as_bytes(), |c| match c {
+        b'-' => Some(Tile::LeftRight),
+        b'|' => Some(Tile::UpDown),
+        b'/' => Some(Tile::Positive),
+        b'\\' => Some(Tile::Negative),
+        b'.' => Some(Tile::Empty),
+        _ => None,
+    });
+}
+
+pub fn validate(content: &str) -> Result<(), Error> {
+    return parse(content).map(|_| ());
+}
+
+fn trace_light(
+    tiles: &Grid<Tile>,
+    energy: &mut Grid<bool>,
+    visited: &mut HashSet<(Dir, Tile, Pos)>,
+    light: Dir,
+    pos: Pos,
+) {
+    // beams still to follow, on the heap as a long beam would overflow the call stack
+    let mut beams = vec![(light, pos)];
+    while let Some((light, pos)) = beams.pop() {
+        let Some(&curr_tile) = tiles.get(pos) else {
+            continue;
+        };
+        // energize current tile if haven't visited
+        energy[pos] = true;
+        if !visited.insert((light, curr_tile, pos)) {
+            continue;
+        }
+        // encode just the directions to take
+        let new_lights = match (light, curr_tile) {
+            (Dir::Left, Tile::LeftRight) => vec![Dir::Left],
+            (Dir::Left, Tile::UpDown) => vec![Dir::Up, Dir::Down],
+            (Dir::Left, Tile::Positive) => vec![Dir::Down],
+            (Dir::Left, Tile::Negative) => vec![Dir::Up],
+            (Dir::Right, Tile::LeftRight) => vec![Dir::Right],
+            (Dir::Right, Tile::UpDown) => vec![Dir::Up, Dir::Down],
+            (Dir::Right, Tile::Positive) => vec![Dir::Up],
+            (Dir::Right, Tile::Negative) => vec![Dir::Down],
+            (Dir::Up, Tile::LeftRight) => vec![Dir::Left, Dir::Right],
+            (Dir::Up, Tile::UpDown) => vec![Dir::Up],
+            (Dir::Up, Tile::Positive) => vec![Dir::Right],
+            (Dir::Up, Tile::Negative) => vec![Dir::Left],
+            (Dir::Down, Tile::LeftRight) => vec![Dir::Left, Dir::Right],
+            (Dir::Down, Tile::UpDown) => vec![Dir::Down],
+            (Dir::Down, Tile::Positive) => vec![Dir::Left],
+            (Dir::Down, Tile::Negative) => vec![Dir::Right],
+            (Dir::Left, Tile::Empty) => vec![Dir::Left],
+            (Dir::Right, Tile::Empty) => vec![Dir::Right],
+            (Dir::Up, Tile::Empty) => vec![Dir::Up],
+            (Dir::Down, Tile::Empty) => vec![Dir::Down],
+        };
+        for new_light in new_lights {
+            beams.push((new_light, pos.step(new_light)));
+        }
+    }
+}
+
+fn debugmap(energy: &Grid<bool>) {
+    let temp = energy.render(|is_energized| match is_energized {
+        true => '#',
+        false => '.',
+    });
+    dump!(Debug, "energized\n{temp}");
+}
+
+fn solve(content: &str) -> Result<i32, Error> {
+    let tiles = parse(content)?;
+    let mut energy = Grid::new(tiles.h(), tiles.w(), false);
+    let mut visited = HashSet::new();
+    trace_light(
+        &tiles,
+        &mut energy,
+        &mut visited,
+        Dir::Right,
+        Pos::new(0, 0),
+    );
+    debugmap(&energy);
+    return Ok(energy.iter().filter(|(_, &state)| state).count() as i32);
+}
+pub fn run(content: &str) -> Result<Answer, Error> {
+    let result = solve(content)?;
+    return Ok(result.into());
+}
+
+pub fn inspect(content: &str) -> Result<Facts, Error> {
+    let tiles = parse(content)?;
+    let count = |kinds: &[Tile]| tiles.iter().filter(|(_, t)| kinds.contains(t)).count();
+    let mut facts = Facts::new();
+    facts.note("rows", tiles.h());
+    facts.note("columns", tiles.w());
+    facts.note("mirrors", count(&[Tile::Positive, Tile::Negative]));
+    facts.note("splitters", count(&[Tile::LeftRight, Tile::UpDown]));
+    return Ok(facts);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let content = String::from(
+            r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....",
+        );
+        let result = solve(&content).unwrap();
+        assert_eq!(result, 46);
+    }
+
+    #[test]
+    fn test_long_beam() {
+        let content = ".".repeat(100_000);
+        assert_eq!(solve(&content).unwrap(), 100_000);
+    }
+}
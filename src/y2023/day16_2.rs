@@ -0,0 +1,134 @@
+use std::{cmp, collections::HashSet};
+
+use crate::{
+    geometry::{Dir, Pos},
+    Answer, Error, Grid,
+};
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+enum Tile {
+    LeftRight,
+    UpDown,
+    Positive,
+    Negative,
+    Empty,
+}
+
+fn parse(content: &str) -> Result<Grid<Tile>, Error> {
+    return Grid::parse_bytes(content.as_bytes(), |c| match c {
+        b'-' => Some(Tile::LeftRight),
+        b'|' => Some(Tile::UpDown),
+        b'/' => Some(Tile::Positive),
+        b'\\' => Some(Tile::Negative),
+        b'.' => Some(Tile::Empty),
+        _ => None,
+    });
+}
+
+pub fn validate(content: &str) -> Result<(), Error> {
+    return parse(content).map(|_| ());
+}
+
+fn trace_light(
+    tiles: &Grid<Tile>,
+    energy: &mut Grid<bool>,
+    visited: &mut HashSet<(Dir, Tile, Pos)>,
+    light: Dir,
+    pos: Pos,
+) {
+    // beams still to follow, on the heap as a long beam would overflow the call stack
+    let mut beams = vec![(light, pos)];
+    while let Some((light, pos)) = beams.pop() {
+        let Some(&curr_tile) = tiles.get(pos) else {
+            continue;
+        };
+        // energize current tile if haven't visited
+        energy[pos] = true;
+        if !visited.insert((light, curr_tile, pos)) {
+            continue;
+        }
+        // encode just the directions to take
+        let new_lights = match (light, curr_tile) {
+            (Dir::Left, Tile::LeftRight) => vec![Dir::Left],
+            (Dir::Left, Tile::UpDown) => vec![Dir::Up, Dir::Down],
+            (Dir::Left, Tile::Positive) => vec![Dir::Down],
+            (Dir::Left, Tile::Negative) => vec![Dir::Up],
+            (Dir::Right, Tile::LeftRight) => vec![Dir::Right],
+            (Dir::Right, Tile::UpDown) => vec![Dir::Up, Dir::Down],
+            (Dir::Right, Tile::Positive) => vec![Dir::Up],
+            (Dir::Right, Tile::Negative) => vec![Dir::Down],
+            (Dir::Up, Tile::LeftRight) => vec![Dir::Left, Dir::Right],
+            (Dir::Up, Tile::UpDown) => vec![Dir::Up],
+            (Dir::Up, Tile::Positive) => vec![Dir::Right],
+            (Dir::Up, Tile::Negative) => vec![Dir::Left],
+            (Dir::Down, Tile::LeftRight) => vec![Dir::Left, Dir::Right],
+            (Dir::Down, Tile::UpDown) => vec![Dir::Down],
+            (Dir::Down, Tile::Positive) => vec![Dir::Left],
+            (Dir::Down, Tile::Negative) => vec![Dir::Right],
+            (Dir::Left, Tile::Empty) => vec![Dir::Left],
+            (Dir::Right, Tile::Empty) => vec![Dir::Right],
+            (Dir::Up, Tile::Empty) => vec![Dir::Up],
+            (Dir::Down, Tile::Empty) => vec![Dir::Down],
+        };
+        for new_light in new_lights {
+            beams.push((new_light, pos.step(new_light)));
+        }
+    }
+}
+
+fn trace_helper(tiles: &Grid<Tile>, light: Dir, pos: Pos) -> i32 {
+    let mut energy = Grid::new(tiles.h(), tiles.w(), false);
+    let mut visited = HashSet::new();
+    trace_light(tiles, &mut energy, &mut visited, light, pos);
+    energy.iter().filter(|(_, &state)| state).count() as i32
+}
+
+fn solve(content: &str) -> Result<i32, Error> {
+    let tiles = parse(content)?;
+    // we trace light from all the edges
+    let (h, w) = (tiles.h() as i64, tiles.w() as i64);
+    let mut biggest = -1;
+    for i in 0..h {
+        biggest = cmp::max(biggest, trace_helper(&tiles, Dir::Right, Pos::new(i, 0)));
+        biggest = cmp::max(biggest, trace_helper(&tiles, Dir::Left, Pos::new(i, w - 1)));
+    }
+    for j in 0..w {
+        biggest = cmp::max(biggest, trace_helper(&tiles, Dir::Down, Pos::new(0, j)));
+        biggest = cmp::max(biggest, trace_helper(&tiles, Dir::Up, Pos::new(h - 1, j)));
+    }
+    Ok(biggest)
+}
+pub fn run(content: &str) -> Result<Answer, Error> {
+    let result = solve(content)?;
+    return Ok(result.into());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let content = String::from(
+            r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....",
+        );
+        let result = solve(&content).unwrap();
+        assert_eq!(result, 51);
+    }
+
+    #[test]
+    fn test_long_beam() {
+        // every edge of a row this long is too slow for a test, one beam is enough
+        let tiles = parse(&".".repeat(100_000)).unwrap();
+        assert_eq!(trace_helper(&tiles, Dir::Right, Pos::new(0, 0)), 100_000);
+    }
+}
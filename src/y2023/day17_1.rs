@@ -0,0 +1,255 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashSet},
+};
+
+use crate::{
+    certificate::{self, Certificate},
+    dump,
+    dump::Level,
+    geometry::{Dir, Pos},
+    Answer, Error, Facts, Grid,
+};
+
+/// The crucible moves at most this many blocks in a row.
+const MAX_STRAIGHT: usize = 3;
+
+fn allowed_dirs(dir: Dir) -> [Dir; 3] {
+    // you can only turn left, right and keep going. Cannot go directly back
+    return [dir, dir.turn_left(), dir.turn_right()];
+}
+
+#[derive(PartialEq, Eq)]
+struct State {
+    dir: Dir,
+    heatloss: i32,
+    consecutive: i32,
+    pos: Pos,
+    paths: Vec<Pos>,
+}
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // order importance by heatloss only, reversed for min heap
+        other.heatloss.cmp(&self.heatloss)
+    }
+}
+
+fn parse(content: &str) -> Result<Grid<i32>, Error> {
+    return Grid::parse_bytes(content.as_bytes(), |b| {
+        b.is_ascii_digit().then(|| i32::from(b - b'0'))
+    });
+}
+
+pub fn validate(content: &str) -> Result<(), Error> {
+    return parse(content).map(|_| ());
+}
+
+fn debugmap(energy: &Grid<bool>) {
+    let temp = energy.render(|is_energized| match is_energized {
+        true => '#',
+        false => '.',
+    });
+    dump!(Debug, "chosen path\n{temp}");
+}
+
+/// The least heat loss and the path with it, both ends included.
+fn dijkstra(heatmap: &Grid<i32>) -> Option<(i32, Vec<Pos>)> {
+    // make visited mask
+    let bounds = heatmap.bounds();
+    let finished = Pos::new(bounds.h as i64 - 1, bounds.w as i64 - 1);
+    let mut visited = HashSet::<(Dir, i32, Pos)>::new();
+    let mut pq = BinaryHeap::<State>::new();
+    // we are on the top left and the first direction can be EITHER down or right, does not matter
+    pq.push(State {
+        dir: Dir::Right,
+        heatloss: -heatmap[(0, 0)], // pre-ignore the first block heat
+        consecutive: 0,             // pre-ignore the first step
+        pos: Pos::new(0, 0),
+        paths: vec![], // save paths chosen by dijkstra
+    });
+    while !pq.is_empty() {
+        let state = pq.pop().unwrap();
+        // bound check
+        let Some(&heat) = heatmap.get(state.pos) else {
+            continue;
+        };
+        // cannot move more than 3 times in a row
+        if state.consecutive > 3 {
+            continue;
+        }
+        // check visited, turns out you need to store consecutive direction and the direction too
+        // https://www.reddit.com/r/adventofcode/comments/18kr07r/comment/kdtho4d/?utm_source=share&utm_medium=web2x&context=3
+        if visited.contains(&(state.dir, state.consecutive, state.pos)) {
+            continue;
+        }
+        visited.insert((state.dir, state.consecutive, state.pos));
+        // add current block
+        let curr_heatloss = state.heatloss + heat;
+        let mut new_paths = state.paths;
+        new_paths.push(state.pos);
+        // check finish
+        if state.pos == finished {
+            if dump::enabled(Level::Debug) {
+                let mut temp = Grid::new(heatmap.h(), heatmap.w(), false);
+                for &pos in &new_paths {
+                    temp[pos] = true;
+                }
+                debugmap(&temp);
+            }
+            return Some((curr_heatloss, new_paths));
+        }
+        // add neighbors
+        for allowed_dir in allowed_dirs(state.dir) {
+            let new_consecutive = if allowed_dir == state.dir {
+                state.consecutive + 1
+            } else {
+                1
+            };
+            let new_state = State {
+                dir: allowed_dir,
+                heatloss: curr_heatloss,
+                consecutive: new_consecutive,
+                pos: state.pos.step(allowed_dir),
+                paths: new_paths.clone(),
+            };
+            pq.push(new_state);
+        }
+    }
+    None
+}
+
+fn solve(content: &str) -> Result<(i32, Vec<Pos>), Error> {
+    let heatmap = parse(content)?;
+    return Ok(dijkstra(&heatmap).expect("Dijkstra is returning None"));
+}
+
+pub fn run(content: &str) -> Result<Answer, Error> {
+    let (result, _) = solve(content)?;
+    return Ok(result.into());
+}
+
+pub fn inspect(content: &str) -> Result<Facts, Error> {
+    let heatmap = parse(content)?;
+    let losses = heatmap.iter().map(|(_, &heat)| heat).collect::<Vec<_>>();
+    let (least, most) = (losses.iter().min().unwrap(), losses.iter().max().unwrap());
+    let mut facts = Facts::new();
+    facts.note("rows", heatmap.h());
+    facts.note("columns", heatmap.w());
+    facts.note("heat loss per block", format!("{least}..={most}"));
+    return Ok(facts);
+}
+
+pub fn certify(content: &str) -> Result<(Answer, Certificate), Error> {
+    let (result, path) = solve(content)?;
+    return Ok((result.into(), Certificate::Path(path)));
+}
+
+/// The path goes from the top left to the bottom right, never turns back or goes straight
+/// for too long, and loses as much heat as the answer says.
+pub fn check(content: &str, answer: &Answer, certificate: &Certificate) -> Result<(), Error> {
+    let heatmap = parse(content)?;
+    let Certificate::Path(path) = certificate else {
+        return Err(Error::rejected("not a path"));
+    };
+    let finish = Pos::new(heatmap.h() as i64 - 1, heatmap.w() as i64 - 1);
+    if path.first() != Some(&Pos::new(0, 0)) || path.last() != Some(&finish) {
+        return Err(Error::rejected("it does not go from corner to corner"));
+    }
+    let runs = certificate::runs(&certificate::directions(path)?);
+    if let Some((dir, len)) = runs.iter().find(|(_, len)| *len > MAX_STRAIGHT) {
+        let msg = format!("it goes {dir:?} {len} times in a row");
+        return Err(Error::rejected(msg));
+    }
+    if runs
+        .windows(2)
+        .any(|turn| turn[1].0 == turn[0].0.opposite())
+    {
+        return Err(Error::rejected("it turns back"));
+    }
+    let mut heatloss = 0;
+    for &pos in &path[1..] {
+        let Some(heat) = heatmap.get(pos) else {
+            return Err(Error::rejected("it leaves the map"));
+        };
+        heatloss += heat;
+    }
+    return certificate::claims(answer, heatloss);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let content = String::from(
+            "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533",
+        );
+        let (result, _) = solve(&content).unwrap();
+        assert_eq!(result, 102);
+
+        let (answer, certificate) = certify(&content).unwrap();
+        assert_eq!(check(&content, &answer, &certificate), Ok(()));
+        assert!(check(&content, &Answer::Int(101), &certificate).is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let content = "119\n911\n991";
+        let path = |cells: &[(i64, i64)]| {
+            Certificate::Path(cells.iter().map(|&(y, x)| Pos::new(y, x)).collect())
+        };
+        let good = path(&[(0, 0), (0, 1), (1, 1), (1, 2), (2, 2)]);
+        assert_eq!(check(content, &Answer::Int(4), &good), Ok(()));
+        for bad in [
+            path(&[(0, 0), (1, 1), (2, 2)]),
+            path(&[(0, 0), (0, 1), (0, 2), (1, 2)]),
+            path(&[(0, 0), (0, 1), (0, 0), (1, 0), (2, 0), (2, 1), (2, 2)]),
+            Certificate::Witness(4),
+        ] {
+            assert!(check(content, &Answer::Int(4), &bad).is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn test_easy1() {
+        // 119
+        // 911
+        // 991
+        let content = String::from(
+            "119
+911
+991",
+        );
+        let (result, _) = solve(&content).unwrap();
+        assert_eq!(result, 4);
+    }
+
+    #[test]
+    fn test_easy2() {
+        let content = String::from(
+            "14999
+23111
+99991",
+        );
+        let (result, _) = solve(&content).unwrap();
+        assert_eq!(result, 11);
+    }
+}
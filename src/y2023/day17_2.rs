@@ -0,0 +1,254 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap},
+};
+
+use crate::{
+    certificate::{self, Certificate},
+    dump,
+    geometry::{Dir, Pos},
+    Answer, Error, Grid,
+};
+
+/// The ultra crucible moves at least and at most this many blocks in a row.
+const STRAIGHT: std::ops::RangeInclusive<usize> = 4..=10;
+
+/// Direction, blocks moved in it so far and position, what Dijkstra visits.
+type Key = (Option<Dir>, i64, Pos);
+
+fn allowed_dirs(dir: Option<Dir>) -> Vec<Dir> {
+    // you can only turn left, right and keep going. Cannot go directly back
+    match dir {
+        None => Dir::ALL.to_vec(), // at the start, nothing to continue
+        Some(dir) => vec![dir, dir.turn_left(), dir.turn_right()],
+    }
+}
+
+#[derive(PartialEq, Eq, Debug)]
+struct State {
+    dir: Option<Dir>,
+    heatloss: i32,
+    consecutive: i64,
+    pos: Pos,
+    // the state this one moved on from, None at the start
+    from: Option<Key>,
+}
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // order importance by heatloss only, reversed for min heap
+        other.heatloss.cmp(&self.heatloss)
+    }
+}
+
+fn parse(content: &str) -> Result<Grid<i32>, Error> {
+    return Grid::parse_bytes(content.as_bytes(), |b| {
+        b.is_ascii_digit().then(|| i32::from(b - b'0'))
+    });
+}
+
+pub fn validate(content: &str) -> Result<(), Error> {
+    return parse(content).map(|_| ());
+}
+
+/// Every block from the start to `key`, retracing the moves through `visited`.
+fn trace(visited: &HashMap<Key, Option<Key>>, key: Key) -> Vec<Pos> {
+    let mut moves = vec![key];
+    while let Some(&Some(from)) = visited.get(moves.last().unwrap()) {
+        moves.push(from);
+    }
+    moves.reverse();
+    let mut path = vec![moves[0].2];
+    for &(dir, _, pos) in &moves[1..] {
+        // only the start has no direction
+        let dir = dir.unwrap();
+        while *path.last().unwrap() != pos {
+            path.push(path.last().unwrap().step(dir));
+        }
+    }
+    return path;
+}
+
+/// The least heat loss and the path with it, both ends included.
+fn dijkstra(heatmap: &Grid<i32>) -> Option<(i32, Vec<Pos>)> {
+    // make visited mask, it also remembers where every state came from
+    let bounds = heatmap.bounds();
+    let finished = Pos::new(bounds.h as i64 - 1, bounds.w as i64 - 1);
+    let mut visited = HashMap::<Key, Option<Key>>::new();
+    let mut pq = BinaryHeap::<State>::new();
+    pq.push(State {
+        dir: None,
+        heatloss: 0,
+        consecutive: 0,
+        pos: Pos::new(0, 0),
+        from: None,
+    });
+    while !pq.is_empty() {
+        let state = pq.pop().unwrap();
+        dump!(Trace, "{state:?}");
+        if state.consecutive > 10 {
+            continue;
+        }
+        // https://www.reddit.com/r/adventofcode/comments/18kr07r/comment/kdtho4d/?utm_source=share&utm_medium=web2x&context=3
+        let key = (state.dir, state.consecutive, state.pos);
+        if visited.contains_key(&key) {
+            continue;
+        }
+        visited.insert(key, state.from);
+        if state.pos == finished {
+            return Some((state.heatloss, trace(&visited, key)));
+        }
+        // add neighbours
+        for allowed_dir in allowed_dirs(state.dir) {
+            let n_moves = if Some(allowed_dir) == state.dir && state.consecutive >= 4 {
+                1
+            } else {
+                4
+            };
+            let new_consecutive = if Some(allowed_dir) == state.dir {
+                state.consecutive + n_moves
+            } else {
+                n_moves
+            };
+            let new_pos = state.pos.step_by(allowed_dir, n_moves);
+            if !bounds.contains(new_pos) {
+                continue;
+            }
+            let mut extra_heatloss = 0;
+            for i in 1..=n_moves {
+                let pos = state.pos.step_by(allowed_dir, i);
+                extra_heatloss += heatmap[pos];
+            }
+            let new_state = State {
+                dir: Some(allowed_dir),
+                heatloss: state.heatloss + extra_heatloss,
+                consecutive: new_consecutive,
+                pos: new_pos,
+                from: Some(key),
+            };
+            pq.push(new_state);
+        }
+    }
+    None
+}
+
+fn solve(content: &str) -> Result<(i32, Vec<Pos>), Error> {
+    let heatmap = parse(content)?;
+    return Ok(dijkstra(&heatmap).expect("Dijkstra is returning None"));
+}
+
+pub fn run(content: &str) -> Result<Answer, Error> {
+    let (result, _) = solve(content)?;
+    return Ok(result.into());
+}
+
+pub fn certify(content: &str) -> Result<(Answer, Certificate), Error> {
+    let (result, path) = solve(content)?;
+    return Ok((result.into(), Certificate::Path(path)));
+}
+
+/// The path goes from the top left to the bottom right, never turns back, goes straight for
+/// neither too short nor too long, and loses as much heat as the answer says.
+pub fn check(content: &str, answer: &Answer, certificate: &Certificate) -> Result<(), Error> {
+    let heatmap = parse(content)?;
+    let Certificate::Path(path) = certificate else {
+        return Err(Error::rejected("not a path"));
+    };
+    let finish = Pos::new(heatmap.h() as i64 - 1, heatmap.w() as i64 - 1);
+    if path.first() != Some(&Pos::new(0, 0)) || path.last() != Some(&finish) {
+        return Err(Error::rejected("it does not go from corner to corner"));
+    }
+    // the last run counts too, the crucible cannot stop before it moved far enough
+    let runs = certificate::runs(&certificate::directions(path)?);
+    if let Some((dir, len)) = runs.iter().find(|(_, len)| !STRAIGHT.contains(len)) {
+        let msg = format!("it goes {dir:?} {len} times in a row");
+        return Err(Error::rejected(msg));
+    }
+    if runs
+        .windows(2)
+        .any(|turn| turn[1].0 == turn[0].0.opposite())
+    {
+        return Err(Error::rejected("it turns back"));
+    }
+    let mut heatloss = 0;
+    for &pos in &path[1..] {
+        let Some(heat) = heatmap.get(pos) else {
+            return Err(Error::rejected("it leaves the map"));
+        };
+        heatloss += heat;
+    }
+    return certificate::claims(answer, heatloss);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        let content = String::from(
+            "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533",
+        );
+        let (result, _) = solve(&content).unwrap();
+        assert_eq!(result, 94);
+
+        let (answer, certificate) = certify(&content).unwrap();
+        assert_eq!(check(&content, &answer, &certificate), Ok(()));
+        assert!(check(&content, &Answer::Int(93), &certificate).is_err());
+    }
+
+    #[test]
+    fn test2() {
+        let content = String::from(
+            "111111111111
+999999999991
+999999999991
+999999999991
+999999999991",
+        );
+        let (result, path) = solve(&content).unwrap();
+        assert_eq!(result, 71);
+        assert_eq!(
+            check(&content, &Answer::Int(71), &Certificate::Path(path)),
+            Ok(())
+        );
+
+        // cheaper, but 11 blocks in a row is more than the ultra crucible can do
+        let straight = (0..12).map(|x| Pos::new(0, x));
+        let down = (1..5).map(|y| Pos::new(y, 11));
+        let too_long = Certificate::Path(straight.chain(down).collect());
+        assert_eq!(
+            check(&content, &Answer::Int(15), &too_long),
+            Err(Error::rejected("it goes Right 11 times in a row"))
+        );
+    }
+
+    #[test]
+    fn test3() {
+        let content = String::from(
+            "11111
+99991
+99991
+99991
+99991",
+        );
+        let (result, _) = solve(&content).unwrap();
+        assert_eq!(result, 8);
+    }
+}
@@ -0,0 +1,232 @@
+use crate::{geometry::Dir, parse_num, polygon::Polygon, Answer, Error, Facts};
+
+struct Command {
+    dir: Dir,
+    length: i64,
+}
+
+fn parse(content: &str) -> Result<Vec<Command>, Error> {
+    let mut commands = vec![];
+    for line in content.lines() {
+        let mut line_iter = line.split_ascii_whitespace();
+        let (Some(dir), Some(length)) = (line_iter.next(), line_iter.next()) else {
+            return Err(Error::parse(format!("expected `<dir> <length>`: {line:?}")));
+        };
+        let length = parse_num(length)?;
+        if length <= 0 {
+            return Err(Error::parse(format!("length must be positive: {line:?}")));
+        }
+        commands.push(Command {
+            dir: match dir {
+                "L" => Dir::Left,
+                "R" => Dir::Right,
+                "U" => Dir::Up,
+                "D" => Dir::Down,
+                other => return Err(Error::parse(format!("bad direction: {other}"))),
+            },
+            length,
+        })
+    }
+    return Ok(commands);
+}
+
+/// Parse without solving, for the fuzz targets.
+pub fn validate(content: &str) -> Result<(), Error> {
+    return lagoon(&parse(content)?).map(|_| ());
+}
+
+fn lagoon(commands: &[Command]) -> Result<Polygon, Error> {
+    return Polygon::from_steps(commands.iter().map(|cmd| (cmd.dir, cmd.length)));
+}
+
+fn solve(content: &str, facts: &mut Facts) -> Result<i128, Error> {
+    let commands = parse(content)?;
+    let lagoon = lagoon(&commands)?;
+    // i don't want to use floodfill
+    // pick's theorem: area = interior + boundary/2 - 1
+    // polygon area can be obtained using shoelace method (polygon area != area asked for day 18)
+    // need to know boundary + interior to know the asked area
+    let polygon_area = lagoon.area();
+    let boundary = lagoon.boundary_points();
+    let interior = lagoon.interior_points();
+    facts.note("polygon area (shoelace)", polygon_area);
+    facts.note("boundary points", boundary);
+    facts.note("interior points (pick)", interior);
+    return Ok(boundary + interior);
+}
+
+pub fn run(content: &str, facts: &mut Facts) -> Result<Answer, Error> {
+    let result = solve(content, facts)?;
+    return Ok(result.into());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::{Bounds, Pos};
+    use proptest::prelude::*;
+    use std::collections::{HashSet, VecDeque};
+
+    /// Dig plan around an x-monotone polygon made of columns, each column spans
+    /// `lo..=hi` rows and overlaps its neighbours so the trench never touches itself.
+    fn columns_to_plan(columns: &[(i32, i32, i32)]) -> String {
+        let mut spans = vec![];
+        for &(width, lo_raw, len) in columns {
+            let lo = match spans.last() {
+                None => lo_raw,
+                Some(&(_, prev_lo, prev_hi)) => lo_raw.clamp(prev_lo - len + 1, prev_hi - 1),
+            };
+            spans.push((width, lo, lo + len));
+        }
+        let mut moves = vec![];
+        let vertical = |from: i32, to: i32| {
+            if to > from {
+                ("D", to - from)
+            } else {
+                ("U", from - to)
+            }
+        };
+        for (i, &(width, lo, _)) in spans.iter().enumerate() {
+            moves.push(("R", width));
+            if let Some(&(_, next_lo, _)) = spans.get(i + 1) {
+                moves.push(vertical(lo, next_lo));
+            }
+        }
+        let &(_, last_lo, last_hi) = spans.last().unwrap();
+        moves.push(vertical(last_lo, last_hi));
+        for (i, &(width, _, hi)) in spans.iter().enumerate().rev() {
+            moves.push(("L", width));
+            if i > 0 {
+                moves.push(vertical(hi, spans[i - 1].2));
+            }
+        }
+        moves.push(vertical(spans[0].2, spans[0].1));
+        return moves
+            .iter()
+            .filter(|(_, len)| *len > 0)
+            .map(|(dir, len)| format!("{dir} {len} (#000000)\n"))
+            .collect();
+    }
+
+    /// Dig the trench cell by cell, then count everything the outside cannot reach.
+    fn floodfill_lagoon(plan: &str) -> i128 {
+        let mut pos = Pos::default();
+        let mut trench = HashSet::from([pos]);
+        for cmd in parse(plan).unwrap() {
+            for _ in 0..cmd.length {
+                pos = pos.step(cmd.dir);
+                trench.insert(pos);
+            }
+        }
+        // shift the trench so that the grid with a one cell margin around it starts at 0, 0
+        let min_y = trench.iter().map(|p| p.y).min().unwrap() - 1;
+        let max_y = trench.iter().map(|p| p.y).max().unwrap() + 1;
+        let min_x = trench.iter().map(|p| p.x).min().unwrap() - 1;
+        let max_x = trench.iter().map(|p| p.x).max().unwrap() + 1;
+        let origin = Pos::new(min_y, min_x);
+        let trench = trench
+            .into_iter()
+            .map(|p| p - origin)
+            .collect::<HashSet<_>>();
+        let bounds = Bounds::new((max_y - min_y + 1) as usize, (max_x - min_x + 1) as usize);
+        let mut outside = HashSet::from([Pos::default()]);
+        let mut queue = VecDeque::from([Pos::default()]);
+        while let Some(pos) = queue.pop_front() {
+            for (_, next) in bounds.neighbours(pos) {
+                if trench.contains(&next) || !outside.insert(next) {
+                    continue;
+                }
+                queue.push_back(next);
+            }
+        }
+        let total = (bounds.h * bounds.w) as i128;
+        return total - outside.len() as i128;
+    }
+
+    proptest! {
+        #[test]
+        fn prop_matches_floodfill(columns in prop::collection::vec((1..4, -6..6, 1..6), 1..7)) {
+            let plan = columns_to_plan(&columns);
+            prop_assert_eq!(solve(&plan, &mut Facts::new()).unwrap(), floodfill_lagoon(&plan));
+        }
+    }
+
+    #[test]
+    fn test() {
+        let content = String::from(
+            "R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)",
+        );
+        let mut facts = Facts::new();
+        let result = solve(&content, &mut facts).unwrap();
+        assert_eq!(result, 62);
+        assert_eq!(facts.get("polygon area (shoelace)"), Some("42"));
+        assert_eq!(facts.get("boundary points"), Some("38"));
+        assert_eq!(facts.get("interior points (pick)"), Some("24"));
+    }
+
+    #[test]
+    fn test_easy1() {
+        let content = String::from(
+            "R 2 (#70c710)
+D 1 (#0dc571)
+L 2 (#5713f0)
+U 1 (#d2c081)",
+        );
+        let result = solve(&content, &mut Facts::new()).unwrap();
+        assert_eq!(result, 6);
+    }
+
+    #[test]
+    fn test_easy2() {
+        let content = String::from(
+            "R 2 (#70c710)
+D 1 (#0dc571)
+L 1 (#0dc571)
+D 1 (#0dc571)
+L 1 (#0dc571)
+U 2 (#d2c081)",
+        );
+        let result = solve(&content, &mut Facts::new()).unwrap();
+        assert_eq!(result, 8);
+    }
+
+    #[test]
+    fn test_easy3() {
+        let content = String::from(
+            "R 3 (#70c710)
+D 1 (#0dc571)
+L 2 (#0dc571)
+D 1 (#0dc571)
+L 1 (#0dc571)
+U 2 (#d2c081)",
+        );
+        let result = solve(&content, &mut Facts::new()).unwrap();
+        assert_eq!(result, 10);
+    }
+
+    #[test]
+    fn test_bad_input() {
+        for content in [
+            "R",
+            "X 6 (#70c710)",
+            "R six (#70c710)",
+            "R -6 (#70c710)",
+            "R 0",
+        ] {
+            assert!(validate(content).is_err(), "{content:?}");
+        }
+    }
+}
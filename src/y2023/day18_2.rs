@@ -0,0 +1,103 @@
+use crate::{geometry::Dir, polygon::Polygon, Answer, Error, Facts};
+
+#[derive(Debug)]
+struct Command {
+    dir: Dir,
+    length: i64,
+}
+
+fn parse(content: &str) -> Result<Vec<Command>, Error> {
+    let mut commands = vec![];
+    for line in content.lines() {
+        // R 6 (#70c710), the real instruction is hidden in the color
+        let bad_line = || Error::parse(format!("expected `<dir> <length> (#rrggbb)`: {line:?}"));
+        let splitted = line.split_once("#").ok_or_else(bad_line)?.1;
+        let code = splitted.strip_suffix(")").ok_or_else(bad_line)?;
+        if code.len() != 6 || !code.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(bad_line());
+        }
+        let (hex, dir) = code.split_at(5);
+        commands.push(Command {
+            dir: match dir {
+                "0" => Dir::Right,
+                "1" => Dir::Down,
+                "2" => Dir::Left,
+                "3" => Dir::Up,
+                other => return Err(Error::parse(format!("bad direction: {other}"))),
+            },
+            length: i64::from_str_radix(hex, 16).map_err(|_| bad_line())?,
+        })
+    }
+    return Ok(commands);
+}
+
+/// Parse without solving, for the fuzz targets.
+pub fn validate(content: &str) -> Result<(), Error> {
+    return lagoon(&parse(content)?).map(|_| ());
+}
+
+fn lagoon(commands: &[Command]) -> Result<Polygon, Error> {
+    return Polygon::from_steps(commands.iter().map(|cmd| (cmd.dir, cmd.length)));
+}
+
+fn solve(content: &str, facts: &mut Facts) -> Result<i128, Error> {
+    let commands = parse(content)?;
+    let lagoon = lagoon(&commands)?;
+    // i don't want to use floodfill
+    // pick's theorem: area = interior + boundary/2 - 1
+    // polygon area can be obtained using shoelace method (polygon area != area asked for day 18)
+    // need to know boundary + interior to know the asked area
+    let polygon_area = lagoon.area();
+    let boundary = lagoon.boundary_points();
+    let interior = lagoon.interior_points();
+    facts.note("polygon area (shoelace)", polygon_area);
+    facts.note("boundary points", boundary);
+    facts.note("interior points (pick)", interior);
+    return Ok(boundary + interior);
+}
+
+pub fn run(content: &str, facts: &mut Facts) -> Result<Answer, Error> {
+    let result = solve(content, facts)?;
+    return Ok(result.into());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let content = String::from(
+            "R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)",
+        );
+        let result = solve(&content, &mut Facts::new()).unwrap();
+        assert_eq!(result, 952408144115);
+    }
+
+    #[test]
+    fn test_bad_input() {
+        for content in [
+            "R 6",
+            "R 6 (#70c710",
+            "R 6 (#70c7)",
+            "R 6 (#70c714)",
+            "R 6 (#70cé10)",
+            "R 6 (#)",
+        ] {
+            assert!(validate(content).is_err(), "{content:?}");
+        }
+    }
+}
@@ -0,0 +1,376 @@
+use std::collections::{HashMap, HashSet};
+
+use itertools::Itertools;
+
+use crate::{
+    dump,
+    dump::Level,
+    parser::{
+        delimited, error_at, int, lines, list, map, one_of, or, pair, parse_all, preceded, spanned,
+        tag, terminated, word, Input, PResult, Spanned,
+    },
+    Answer, Error, Facts,
+};
+
+#[derive(Debug, Clone)]
+enum Attr {
+    X,
+    M,
+    A,
+    S,
+}
+#[derive(Debug, Clone)]
+enum Comp {
+    Lt,
+    Gt,
+}
+
+#[derive(Debug)]
+enum WorkflowType {
+    Rejected,
+    Accepted,
+    Continue(String),
+}
+
+#[derive(Debug)]
+enum Rule {
+    Evaluate(Attr, Comp, i32, WorkflowType),
+    Immediate(WorkflowType),
+}
+
+#[derive(Debug)]
+struct Workflow {
+    #[allow(dead_code)]
+    name: String,
+    rules: Vec<Rule>,
+}
+
+#[derive(Default, Debug)]
+struct Part {
+    x: i32,
+    m: i32,
+    a: i32,
+    s: i32,
+}
+impl Part {
+    fn xmas_sum(&self) -> i32 {
+        self.x + self.m + self.a + self.s
+    }
+}
+
+fn next_workflow<'a>() -> impl Fn(Input<'a>) -> PResult<'a, WorkflowType> {
+    return map(word(), |name| match name {
+        "R" => WorkflowType::Rejected,
+        "A" => WorkflowType::Accepted,
+        normal_wf => WorkflowType::Continue(normal_wf.to_owned()),
+    });
+}
+
+fn attr<'a>() -> impl Fn(Input<'a>) -> PResult<'a, Attr> {
+    return one_of([
+        ("x", Attr::X),
+        ("m", Attr::M),
+        ("a", Attr::A),
+        ("s", Attr::S),
+    ]);
+}
+
+/// `px{a<2006:qkq,m>2090:A,rfg}`
+fn workflow<'a>() -> impl Fn(Input<'a>) -> PResult<'a, Spanned<Workflow>> {
+    let comp = one_of([("<", Comp::Lt), (">", Comp::Gt)]);
+    let evaluate = map(
+        pair(
+            pair(attr(), comp),
+            pair(int(), preceded(tag(":"), next_workflow())),
+        ),
+        |((attr, comp), (value, next))| Rule::Evaluate(attr, comp, value, next),
+    );
+    let rule = or(evaluate, map(next_workflow(), Rule::Immediate));
+    let rules = delimited(tag("{"), list(rule, tag(",")), tag("}"));
+    return spanned(map(pair(word(), rules), |(name, rules)| Workflow {
+        name: name.to_owned(),
+        rules,
+    }));
+}
+
+/// Workflows the rules of `wf` send parts to.
+fn targets(wf: &Workflow) -> impl Iterator<Item = &str> {
+    return wf.rules.iter().filter_map(|rule| match rule {
+        Rule::Evaluate(_, _, _, WorkflowType::Continue(next))
+        | Rule::Immediate(WorkflowType::Continue(next)) => Some(next.as_str()),
+        _ => None,
+    });
+}
+
+/// A part that goes around a loop of workflows never gets a verdict. Depth first from `in`
+/// with an explicit stack, a workflow that comes up again while it is still on the stack
+/// closes a loop.
+fn check_loops(content: &str, parsed: &[Spanned<Workflow>]) -> Result<(), Error> {
+    let by_name: HashMap<&str, &Spanned<Workflow>> = parsed
+        .iter()
+        .map(|wf| (wf.value.name.as_str(), wf))
+        .collect();
+    let mut seen = HashSet::from(["in"]);
+    let mut on_stack = HashSet::from(["in"]);
+    let mut stack = vec![("in", 0)];
+    while let Some((name, tried)) = stack.last_mut() {
+        let (name, wf) = (*name, by_name[*name]);
+        let Some(next) = targets(&wf.value).nth(*tried) else {
+            on_stack.remove(name);
+            stack.pop();
+            continue;
+        };
+        *tried += 1;
+        if on_stack.contains(next) {
+            let msg = format!("workflow {name} sends parts in a loop");
+            return Err(error_at(content, wf.span.start, msg));
+        }
+        if seen.insert(next) {
+            on_stack.insert(next);
+            stack.push((next, 0));
+        }
+    }
+    return Ok(());
+}
+
+/// The checks that need every workflow, reported at the offending one.
+fn check_workflows(
+    content: &str,
+    parsed: Vec<Spanned<Workflow>>,
+) -> Result<HashMap<String, Workflow>, Error> {
+    let names: HashSet<&str> = parsed.iter().map(|wf| wf.value.name.as_str()).collect();
+    for Spanned { value: wf, span } in &parsed {
+        // otherwise a part could fall through every rule
+        if !matches!(wf.rules.last(), Some(Rule::Immediate(_))) {
+            return Err(error_at(
+                content,
+                span.start,
+                "workflow must end with a fallback",
+            ));
+        }
+        for next in targets(wf) {
+            if !names.contains(next) {
+                let msg = format!("unknown workflow {next:?}");
+                return Err(error_at(content, span.start, msg));
+            }
+        }
+    }
+    if !names.contains("in") {
+        return Err(Error::parse("there is no `in` workflow"));
+    }
+    check_loops(content, &parsed)?;
+    return Ok(parsed
+        .into_iter()
+        .map(|wf| (wf.value.name.clone(), wf.value))
+        .collect());
+}
+
+/// `{x=787,m=2655,a=1222,s=2876}`
+fn part<'a>() -> impl Fn(Input<'a>) -> PResult<'a, Part> {
+    let rating = pair(attr(), preceded(tag("="), int::<i32>()));
+    let ratings = delimited(tag("{"), list(rating, tag(",")), tag("}"));
+    return map(ratings, |ratings| {
+        let mut part = Part::default();
+        for (attr, value) in ratings {
+            match attr {
+                Attr::X => part.x = value,
+                Attr::M => part.m = value,
+                Attr::A => part.a = value,
+                Attr::S => part.s = value,
+            }
+        }
+        return part;
+    });
+}
+
+fn parse(content: &str) -> Result<(HashMap<String, Workflow>, Vec<Part>), Error> {
+    let grammar = pair(terminated(lines(workflow()), tag("\n\n")), lines(part()));
+    let (workflows, parts) = parse_all(content, grammar)?;
+    return Ok((check_workflows(content, workflows)?, parts));
+}
+
+/// Parse without solving, for the fuzz targets.
+pub fn validate(content: &str) -> Result<(), Error> {
+    return parse(content).map(|_| ());
+}
+
+fn check_accepted(workflows: &HashMap<String, Workflow>, part: &Part) -> bool {
+    let mut curr_name = &String::from("in");
+    loop {
+        let curr_wf = &workflows[curr_name];
+        for rule in &curr_wf.rules {
+            match rule {
+                Rule::Evaluate(attr, comp, value, next_wf) => {
+                    let rule_accepted = match (attr, comp) {
+                        (Attr::X, Comp::Gt) => part.x > *value,
+                        (Attr::M, Comp::Gt) => part.m > *value,
+                        (Attr::A, Comp::Gt) => part.a > *value,
+                        (Attr::S, Comp::Gt) => part.s > *value,
+                        (Attr::X, Comp::Lt) => part.x < *value,
+                        (Attr::M, Comp::Lt) => part.m < *value,
+                        (Attr::A, Comp::Lt) => part.a < *value,
+                        (Attr::S, Comp::Lt) => part.s < *value,
+                    };
+                    if rule_accepted {
+                        match next_wf {
+                            WorkflowType::Accepted => return true,
+                            WorkflowType::Rejected => return false,
+                            WorkflowType::Continue(wf_name) => {
+                                curr_name = wf_name;
+                                break;
+                            }
+                        }
+                    }
+                }
+                Rule::Immediate(next_wf) => match next_wf {
+                    WorkflowType::Accepted => return true,
+                    WorkflowType::Rejected => return false,
+                    WorkflowType::Continue(wf_name) => {
+                        curr_name = wf_name;
+                        break;
+                    }
+                },
+            }
+        }
+    }
+}
+
+fn solve(content: &str) -> Result<i32, Error> {
+    let (workflows, parts) = parse(content)?;
+    if dump::enabled(Level::Debug) {
+        for (name, wf) in workflows.iter().sorted_by_key(|(name, _)| *name) {
+            dump!(Debug, "{name} => {wf:?}");
+        }
+        for part in &parts {
+            dump!(Debug, "{part:?}");
+        }
+    }
+    let mut total = 0;
+    for part in parts {
+        if check_accepted(&workflows, &part) {
+            total += part.xmas_sum();
+        }
+    }
+    return Ok(total);
+}
+
+pub fn run(content: &str) -> Result<Answer, Error> {
+    let result = solve(content)?;
+    return Ok(result.into());
+}
+
+/// Workflows on the longest way from `in` to a verdict, `in` included. `parse` rejects loops,
+/// so each workflow is counted once its targets are, shared ones only once.
+fn chain_depth(workflows: &HashMap<String, Workflow>) -> usize {
+    let mut depth: HashMap<&str, usize> = HashMap::new();
+    let mut stack = vec!["in"];
+    while let Some(&name) = stack.last() {
+        if depth.contains_key(name) {
+            stack.pop();
+            continue;
+        }
+        let workflow = &workflows[name];
+        let missing = targets(workflow)
+            .filter(|next| !depth.contains_key(next))
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            stack.extend(missing);
+            continue;
+        }
+        let deepest = targets(workflow).map(|next| depth[next]).max();
+        depth.insert(name, 1 + deepest.unwrap_or(0));
+        stack.pop();
+    }
+    return depth["in"];
+}
+
+pub fn inspect(content: &str) -> Result<Facts, Error> {
+    let (workflows, parts) = parse(content)?;
+    let most_rules = workflows
+        .values()
+        .map(|wf| wf.rules.len())
+        .max()
+        .unwrap_or(0);
+    let mut facts = Facts::new();
+    facts.note("workflows", workflows.len());
+    facts.note("parts", parts.len());
+    facts.note("most rules in a workflow", most_rules);
+    facts.note("deepest chain from in", chain_depth(&workflows));
+    return Ok(facts);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let content = String::from(
+            "px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}",
+        );
+        let result = solve(&content).unwrap();
+        assert_eq!(result, 19114);
+
+        let facts = inspect(&content).unwrap();
+        assert_eq!(facts.get("workflows"), Some("11"));
+        assert_eq!(facts.get("most rules in a workflow"), Some("3"));
+        assert_eq!(facts.get("deepest chain from in"), Some("4"));
+        let looping = "in{x<5:in,A}\n\n{x=1,m=2,a=3,s=4}";
+        assert!(inspect(looping).is_err());
+
+        // 2^60 ways through, one chain 61 workflows deep
+        let mut shared = String::from("in{x<5:w0,w0}\n");
+        for i in 0..60 {
+            shared += &format!("w{i}{{x<5:w{},w{}}}\n", i + 1, i + 1);
+        }
+        shared += "w60{A}\n\n{x=1,m=2,a=3,s=4}";
+        let facts = inspect(&shared).unwrap();
+        assert_eq!(facts.get("deepest chain from in"), Some("62"));
+    }
+
+    #[test]
+    fn test_loop() {
+        // a part with x < 5 and m < 5 would go back and forth forever
+        let content = "in{x<5:aa,A}\naa{m<5:in,R}\n\n{x=1,m=2,a=3,s=4}";
+        let Err(Error::Parse(msg)) = solve(content) else {
+            panic!("a loop must not parse");
+        };
+        assert!(msg.contains("workflow aa sends parts in a loop"), "{msg}");
+    }
+
+    #[test]
+    fn test_bad_input() {
+        for content in [
+            "in{A}",
+            "in{A\n\n{x=1,m=2,a=3,s=4}",
+            "in{x<5:A}\n\n{x=1,m=2,a=3,s=4}",
+            "in{y<5:A,R}\n\n{x=1,m=2,a=3,s=4}",
+            "in{x=5:A,R}\n\n{x=1,m=2,a=3,s=4}",
+            "in{x<five:A,R}\n\n{x=1,m=2,a=3,s=4}",
+            "in{x<5:px,R}\n\n{x=1,m=2,a=3,s=4}",
+            "px{A}\n\n{x=1,m=2,a=3,s=4}",
+            "in{A}\n\n{x=1,m=2,a=3,s=4",
+            "in{A}\n\n{x=1,y=2}",
+            "in{A}\n\n{x1}",
+            "in{x<5:aa,A}\naa{m<5:in,R}\n\n{x=1,m=2,a=3,s=4}",
+        ] {
+            assert!(validate(content).is_err(), "{content:?}");
+        }
+    }
+}
@@ -0,0 +1,310 @@
+use std::collections::{HashMap, HashSet};
+
+use itertools::Itertools;
+
+use crate::{
+    dump,
+    dump::Level,
+    interval::{Interval, NBox},
+    parser::{
+        delimited, error_at, int, lines, list, map, one_of, or, pair, parse_all, preceded, rest,
+        spanned, tag, terminated, word, Input, PResult, Spanned,
+    },
+    Answer, Error,
+};
+
+#[derive(Debug, Clone)]
+enum Attr {
+    X,
+    M,
+    A,
+    S,
+}
+#[derive(Debug, Clone)]
+enum Comp {
+    Lt,
+    Gt,
+}
+
+#[derive(Debug)]
+enum WorkflowType {
+    Rejected,
+    Accepted,
+    Continue(String),
+}
+
+#[derive(Debug)]
+enum Rule {
+    Evaluate(Attr, Comp, i64, WorkflowType),
+    Immediate(WorkflowType),
+}
+
+#[derive(Debug)]
+struct Workflow {
+    #[allow(dead_code)]
+    name: String,
+    rules: Vec<Rule>,
+}
+
+impl Attr {
+    /// Axis of the attribute in a part box.
+    fn axis(&self) -> usize {
+        match self {
+            Attr::X => 0,
+            Attr::M => 1,
+            Attr::A => 2,
+            Attr::S => 3,
+        }
+    }
+}
+
+/// Every combination of ratings, each from 1 to 4000.
+fn all_parts() -> NBox<4> {
+    NBox::new([Interval::new(1, 4000); 4])
+}
+
+fn next_workflow<'a>() -> impl Fn(Input<'a>) -> PResult<'a, WorkflowType> {
+    return map(word(), |name| match name {
+        "R" => WorkflowType::Rejected,
+        "A" => WorkflowType::Accepted,
+        normal_wf => WorkflowType::Continue(normal_wf.to_owned()),
+    });
+}
+
+fn attr<'a>() -> impl Fn(Input<'a>) -> PResult<'a, Attr> {
+    return one_of([
+        ("x", Attr::X),
+        ("m", Attr::M),
+        ("a", Attr::A),
+        ("s", Attr::S),
+    ]);
+}
+
+/// `px{a<2006:qkq,m>2090:A,rfg}`
+fn workflow<'a>() -> impl Fn(Input<'a>) -> PResult<'a, Spanned<Workflow>> {
+    let comp = one_of([("<", Comp::Lt), (">", Comp::Gt)]);
+    let evaluate = map(
+        pair(
+            pair(attr(), comp),
+            pair(int(), preceded(tag(":"), next_workflow())),
+        ),
+        |((attr, comp), (value, next))| Rule::Evaluate(attr, comp, value, next),
+    );
+    let rule = or(evaluate, map(next_workflow(), Rule::Immediate));
+    let rules = delimited(tag("{"), list(rule, tag(",")), tag("}"));
+    return spanned(map(pair(word(), rules), |(name, rules)| Workflow {
+        name: name.to_owned(),
+        rules,
+    }));
+}
+
+/// Workflows the rules of `wf` send parts to.
+fn targets(wf: &Workflow) -> impl Iterator<Item = &str> {
+    return wf.rules.iter().filter_map(|rule| match rule {
+        Rule::Evaluate(_, _, _, WorkflowType::Continue(next))
+        | Rule::Immediate(WorkflowType::Continue(next)) => Some(next.as_str()),
+        _ => None,
+    });
+}
+
+/// A part that goes around a loop of workflows never gets a verdict. Depth first from `in`
+/// with an explicit stack, a workflow that comes up again while it is still on the stack
+/// closes a loop.
+fn check_loops(content: &str, parsed: &[Spanned<Workflow>]) -> Result<(), Error> {
+    let by_name: HashMap<&str, &Spanned<Workflow>> = parsed
+        .iter()
+        .map(|wf| (wf.value.name.as_str(), wf))
+        .collect();
+    let mut seen = HashSet::from(["in"]);
+    let mut on_stack = HashSet::from(["in"]);
+    let mut stack = vec![("in", 0)];
+    while let Some((name, tried)) = stack.last_mut() {
+        let (name, wf) = (*name, by_name[*name]);
+        let Some(next) = targets(&wf.value).nth(*tried) else {
+            on_stack.remove(name);
+            stack.pop();
+            continue;
+        };
+        *tried += 1;
+        if on_stack.contains(next) {
+            let msg = format!("workflow {name} sends parts in a loop");
+            return Err(error_at(content, wf.span.start, msg));
+        }
+        if seen.insert(next) {
+            on_stack.insert(next);
+            stack.push((next, 0));
+        }
+    }
+    return Ok(());
+}
+
+/// The checks that need every workflow, reported at the offending one.
+fn check_workflows(
+    content: &str,
+    parsed: Vec<Spanned<Workflow>>,
+) -> Result<HashMap<String, Workflow>, Error> {
+    let names: HashSet<&str> = parsed.iter().map(|wf| wf.value.name.as_str()).collect();
+    for Spanned { value: wf, span } in &parsed {
+        // otherwise a part could fall through every rule
+        if !matches!(wf.rules.last(), Some(Rule::Immediate(_))) {
+            return Err(error_at(
+                content,
+                span.start,
+                "workflow must end with a fallback",
+            ));
+        }
+        for next in targets(wf) {
+            if !names.contains(next) {
+                let msg = format!("unknown workflow {next:?}");
+                return Err(error_at(content, span.start, msg));
+            }
+        }
+    }
+    if !names.contains("in") {
+        return Err(Error::parse("there is no `in` workflow"));
+    }
+    check_loops(content, &parsed)?;
+    return Ok(parsed
+        .into_iter()
+        .map(|wf| (wf.value.name.clone(), wf.value))
+        .collect());
+}
+
+fn parse(content: &str) -> Result<HashMap<String, Workflow>, Error> {
+    // the parts are irrelevant for part 2
+    let grammar = terminated(lines(workflow()), pair(tag("\n\n"), rest()));
+    let workflows = parse_all(content, grammar)?;
+    return check_workflows(content, workflows);
+}
+
+/// Parse without solving, for the fuzz targets.
+pub fn validate(content: &str) -> Result<(), Error> {
+    return parse(content).map(|_| ());
+}
+
+/// Workflow, rule index and the parts that reach that rule, still to be counted.
+type Pending<'a> = Vec<(&'a str, usize, NBox<4>)>;
+
+fn send<'a>(wf_type: &'a WorkflowType, parts: NBox<4>, pending: &mut Pending<'a>) -> i128 {
+    match wf_type {
+        WorkflowType::Accepted => parts.volume(),
+        WorkflowType::Rejected => 0,
+        WorkflowType::Continue(next_wf) => {
+            pending.push((next_wf, 0, parts));
+            0
+        }
+    }
+}
+
+fn count_all_accepted(workflows: &HashMap<String, Workflow>, parts: NBox<4>) -> i128 {
+    // a long chain of workflows overflows the call stack, so the boxes wait on the heap
+    let mut pending = vec![("in", 0, parts)];
+    let mut total = 0;
+    while let Some((wf_name, wf_idx, parts)) = pending.pop() {
+        let rule = &workflows[wf_name].rules[wf_idx];
+        total += match rule {
+            Rule::Immediate(wf_type) => send(wf_type, parts, &mut pending),
+            Rule::Evaluate(attr, comp, value, wf_type) => {
+                // the rule cuts the box in two: the matching side goes to the rule's workflow,
+                // the other side carries on with the next rule. either side can be empty
+                let (matching, rest) = match comp {
+                    Comp::Lt => parts.split(attr.axis(), *value),
+                    Comp::Gt => match value.checked_add(1) {
+                        Some(above) => {
+                            let (rest, matching) = parts.split(attr.axis(), above);
+                            (matching, rest)
+                        }
+                        None => (None, Some(parts)),
+                    },
+                };
+                if let Some(rest) = rest {
+                    pending.push((wf_name, wf_idx + 1, rest));
+                }
+                matching.map_or(0, |parts| send(wf_type, parts, &mut pending))
+            }
+        };
+    }
+    return total;
+}
+
+fn solve(content: &str) -> Result<i128, Error> {
+    let workflows = parse(content)?;
+    if dump::enabled(Level::Debug) {
+        for (name, wf) in workflows.iter().sorted_by_key(|(name, _)| *name) {
+            dump!(Debug, "{name} => {wf:?}");
+        }
+    }
+    return Ok(count_all_accepted(&workflows, all_parts()));
+}
+
+pub fn run(content: &str) -> Result<Answer, Error> {
+    let result = solve(content)?;
+    return Ok(result.into());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let content = String::from(
+            "px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}",
+        );
+        let result = solve(&content).unwrap();
+        assert_eq!(result, 167409079868000);
+    }
+
+    #[test]
+    fn test_loop() {
+        // a part with x < 5 and m < 5 would go back and forth forever
+        let content = "in{x<5:aa,A}\naa{m<5:in,R}\n\n{x=1,m=2,a=3,s=4}";
+        let Err(Error::Parse(msg)) = solve(content) else {
+            panic!("a loop must not parse");
+        };
+        assert!(msg.contains("workflow aa sends parts in a loop"), "{msg}");
+    }
+
+    #[test]
+    fn test_bad_input() {
+        for content in [
+            "in{A}",
+            "in{A\n\n{x=1,m=2,a=3,s=4}",
+            "in{x<5:A}\n\n{x=1,m=2,a=3,s=4}",
+            "in{y<5:A,R}\n\n{x=1,m=2,a=3,s=4}",
+            "in{x=5:A,R}\n\n{x=1,m=2,a=3,s=4}",
+            "in{x<five:A,R}\n\n{x=1,m=2,a=3,s=4}",
+            "in{x<5:px,R}\n\n{x=1,m=2,a=3,s=4}",
+            "px{A}\n\n{x=1,m=2,a=3,s=4}",
+            "in{x<5:aa,A}\naa{m<5:in,R}\n\n{x=1,m=2,a=3,s=4}",
+        ] {
+            assert!(validate(content).is_err(), "{content:?}");
+        }
+    }
+
+    #[test]
+    fn test_long_chain() {
+        let mut content = String::from("in{a<4001:w0,R}\n");
+        for i in 0..100_000 {
+            content += &format!("w{i}{{a<4001:w{},R}}\n", i + 1);
+        }
+        content += "w100000{A}\n\n{x=1,m=1,a=1,s=1}";
+        assert_eq!(solve(&content).unwrap(), 4000_i128.pow(4));
+    }
+}
@@ -1,6 +1,16 @@
-use std::{
-    collections::{HashMap, VecDeque},
-    fs,
+use std::collections::{HashMap, VecDeque};
+
+use itertools::Itertools;
+
+use crate::{
+    dump,
+    dump::Level,
+    params::Param,
+    parser::{
+        error_at, lines, list, map, one_of, or, pair, parse_all, spanned, tag, terminated, word,
+        Input, PResult, Spanned,
+    },
+    Answer, Error, Params,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -12,9 +22,11 @@ enum Pulse {
 #[derive(Debug)]
 enum Module {
     Broad {
+        #[allow(dead_code)]
         name: String,
     },
     Flip {
+        #[allow(dead_code)]
         name: String,
         state: Pulse,
     },
@@ -24,7 +36,7 @@ enum Module {
     },
 }
 impl Module {
-    fn send_pulse(&mut self, sender: &String, received_pulse: Pulse) -> Option<Pulse> {
+    fn send_pulse(&mut self, sender: &str, received_pulse: Pulse) -> Option<Pulse> {
         match self {
             Module::Broad { name: _ } => None,
             Module::Flip { name: _, state } => {
@@ -32,7 +44,7 @@ impl Module {
                     return None;
                 }
                 // save current state and toggle it
-                let saved_state = state.clone();
+                let saved_state = *state;
                 *state = match state {
                     Pulse::Hi => Pulse::Lo,
                     Pulse::Lo => Pulse::Hi,
@@ -49,7 +61,7 @@ impl Module {
                 input_states,
             } => {
                 assert!(input_states.contains_key(sender)); // this is guaranteed from parsing
-                input_states.insert(sender.clone(), received_pulse);
+                input_states.insert(sender.to_owned(), received_pulse);
                 match input_states.iter().all(|(_, &pulse)| pulse == Pulse::Hi) {
                     true => Some(Pulse::Lo),
                     false => Some(Pulse::Hi),
@@ -59,7 +71,26 @@ impl Module {
     }
 }
 
-fn parse(content: &String) -> (HashMap<String, Module>, HashMap<String, Vec<String>>) {
+/// Module prefix, name and output names, the broadcaster gets the prefix `b`.
+type Row<'a> = (char, &'a str, Vec<&'a str>);
+
+/// `%a -> b, c`
+fn row<'a>() -> impl Fn(Input<'a>) -> PResult<'a, Spanned<Row<'a>>> {
+    let module = or(
+        map(tag("broadcaster"), |name| ('b', name)),
+        pair(one_of([("%", '%'), ("&", '&')]), word()),
+    );
+    let outputs = list(word(), tag(", "));
+    return spanned(map(
+        pair(terminated(module, tag(" -> ")), outputs),
+        |((prefix, name), out_names)| (prefix, name, out_names),
+    ));
+}
+
+// module name -> names of the modules it sends to
+type Transition = HashMap<String, Vec<String>>;
+
+fn parse(content: &str) -> Result<(HashMap<String, Module>, Transition), Error> {
     // broadcaster -> a, b, c
     // %a -> b
     // %b -> c
@@ -67,48 +98,40 @@ fn parse(content: &String) -> (HashMap<String, Module>, HashMap<String, Vec<Stri
     // &inv -> a
 
     // the parsing is a bit unique because we must know both right and left side first
-    let mut in2outs: HashMap<String, Vec<String>> = HashMap::new();
+    let mut in2outs: Transition = HashMap::new();
     let mut out2ins: HashMap<String, Vec<String>> = HashMap::new();
-    for row in content.lines() {
-        let (left, right) = row.split_once(" -> ").unwrap();
-        let prefix = left.chars().nth(0).unwrap();
-        let in_name = match prefix {
-            'b' => "broadcaster",
-            _ => &left[1..],
-        }
-        .to_owned();
-        let out_names = right.split(", ").map(|s| s.to_owned()).collect::<Vec<_>>();
-        in2outs
-            .entry(in_name.clone())
-            .or_default()
-            .extend(out_names.iter().cloned());
-        for out_name in out_names {
-            out2ins.entry(out_name).or_default().push(in_name.clone());
-        }
-    }
-    // modules
     let mut modules = HashMap::new();
-    for row in content.lines() {
-        let (left, _) = row.split_once(" -> ").unwrap();
-        let prefix = left.chars().nth(0).unwrap();
-        let name = match prefix {
-            'b' => "broadcaster",
-            _ => &left[1..],
-        }
-        .to_owned();
+    for Spanned { value, span } in parse_all(content, lines(row()))? {
+        let (prefix, name, out_names) = value;
+        let name = name.to_owned();
         let module = match prefix {
             'b' => Module::Broad { name: name.clone() },
             '%' => Module::Flip {
                 name: name.clone(),
                 state: Pulse::Lo,
             },
-            '&' => Module::Conj {
+            _ => Module::Conj {
                 name: name.clone(),
                 input_states: HashMap::new(),
             },
-            other => unreachable!("bad prefix: {other}"),
         };
-        modules.insert(name, module);
+        if modules.insert(name.clone(), module).is_some() {
+            let msg = format!("duplicate module: {name:?}");
+            return Err(error_at(content, span.start, msg));
+        }
+        for out_name in out_names {
+            in2outs
+                .entry(name.clone())
+                .or_default()
+                .push(out_name.to_owned());
+            out2ins
+                .entry(out_name.to_owned())
+                .or_default()
+                .push(name.clone());
+        }
+    }
+    if !modules.contains_key("broadcaster") {
+        return Err(Error::parse("there is no broadcaster"));
     }
     // just for conj module, we need to keep track the input names
     for (_, module) in modules.iter_mut() {
@@ -116,12 +139,17 @@ fn parse(content: &String) -> (HashMap<String, Module>, HashMap<String, Vec<Stri
             name, input_states, ..
         } = module
         {
-            for out_name in out2ins.get(name).unwrap() {
+            for out_name in out2ins.get(name).into_iter().flatten() {
                 input_states.insert(out_name.clone(), Pulse::Lo);
             }
         }
     }
-    (modules, in2outs)
+    return Ok((modules, in2outs));
+}
+
+/// Parse without solving, for the fuzz targets.
+pub fn validate(content: &str) -> Result<(), Error> {
+    return parse(content).map(|_| ());
 }
 
 fn simulate(
@@ -158,30 +186,45 @@ fn simulate(
     (lo_send, hi_send)
 }
 
-fn solve(content: &String) -> i32 {
-    let (mut modules, transition) = parse(content);
+/// The parsed modules and where each one sends its pulses, sorted by name.
+fn dump_network(modules: &HashMap<String, Module>, transition: &Transition) {
+    if !dump::enabled(Level::Debug) {
+        return;
+    }
+    dump!(Debug, "MODULES");
+    for (k, v) in modules.iter().sorted_by_key(|(k, _)| *k) {
+        dump!(Debug, "{k:?} {v:?}");
+    }
+    dump!(Debug, "TRANSITION");
+    for (k, v) in transition.iter().sorted_by_key(|(k, _)| *k) {
+        dump!(Debug, "{k} {v:?}");
+    }
+}
+
+fn solve(content: &str, presses: usize) -> Result<i32, Error> {
+    let (mut modules, transition) = parse(content)?;
     let mut lo_send_total = 0;
     let mut hi_send_total = 0;
-    // println!("MODULES");
-    // for (k, v) in &modules {
-    //     println!("{k:?} {v:?}");
-    // }
-    // println!("\nTRANSITION");
-    // for (k, v) in &transition {
-    //     println!("{k} {v:?}");
-    // }
-    for _ in 0..1000 {
+    dump_network(&modules, &transition);
+    for _ in 0..presses {
         let (lo_send, hi_send) = simulate(&mut modules, &transition);
         lo_send_total += lo_send;
         hi_send_total += hi_send;
     }
-    lo_send_total * hi_send_total
+    return Ok(lo_send_total * hi_send_total);
 }
 
-fn main() {
-    let content = fs::read_to_string("inputs/day20.txt").expect("input for day 20 is missing");
-    let result = solve(&content);
-    println!("day 20 part 1: {}", result);
+pub const PARAMS: &[Param] = &[Param {
+    name: "presses",
+    default: 1000,
+    min: 0,
+    max: i64::MAX,
+    help: "button presses",
+}];
+
+pub fn run(content: &str, params: &Params) -> Result<Answer, Error> {
+    let result = solve(content, params.get("presses")?)?;
+    return Ok(result.into());
 }
 
 #[cfg(test)]
@@ -197,8 +240,10 @@ mod tests {
 %c -> inv
 &inv -> a",
         );
-        let result = solve(&content);
+        let result = solve(&content, 1000).unwrap();
         assert_eq!(result, 32000000);
+        // a single press sends 8 low and 4 high pulses
+        assert_eq!(solve(&content, 1).unwrap(), 8 * 4);
     }
 
     #[test]
@@ -210,7 +255,22 @@ mod tests {
 %b -> con
 &con -> output",
         );
-        let result = solve(&content);
+        let result = solve(&content, 1000).unwrap();
         assert_eq!(result, 11687500);
     }
+
+    #[test]
+    fn test_bad_input() {
+        for content in [
+            "%a -> b",
+            "broadcaster a",
+            "broadcast -> a",
+            "broadcaster -> a\n% -> a",
+            "broadcaster -> a\n#a -> b",
+            "broadcaster -> a\n%a -> ",
+            "broadcaster -> a\n%a -> b\n&a -> b",
+        ] {
+            assert!(validate(content).is_err(), "{content:?}");
+        }
+    }
 }
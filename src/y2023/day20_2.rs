@@ -0,0 +1,304 @@
+use std::collections::{HashMap, VecDeque};
+
+use itertools::Itertools;
+
+use crate::{
+    dump,
+    dump::Level,
+    parser::{
+        error_at, lines, list, map, one_of, or, pair, parse_all, spanned, tag, terminated, word,
+        Input, PResult, Spanned,
+    },
+    Answer, Error, Facts, Progress,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pulse {
+    Lo,
+    Hi,
+}
+
+#[derive(Debug)]
+enum Module {
+    Broad {
+        #[allow(dead_code)]
+        name: String,
+    },
+    Flip {
+        #[allow(dead_code)]
+        name: String,
+        state: Pulse,
+    },
+    Conj {
+        name: String,
+        input_states: HashMap<String, Pulse>,
+    },
+}
+impl Module {
+    fn send_pulse(&mut self, sender: &str, received_pulse: Pulse) -> Option<Pulse> {
+        match self {
+            Module::Broad { name: _ } => None,
+            Module::Flip { name: _, state } => {
+                if received_pulse == Pulse::Hi {
+                    return None;
+                }
+                // save current state and toggle it
+                let saved_state = *state;
+                *state = match state {
+                    Pulse::Hi => Pulse::Lo,
+                    Pulse::Lo => Pulse::Hi,
+                };
+                // use saved state to perform duty
+                match (received_pulse, saved_state) {
+                    (Pulse::Lo, Pulse::Lo) => Some(Pulse::Hi),
+                    (Pulse::Lo, Pulse::Hi) => Some(Pulse::Lo),
+                    _ => None,
+                }
+            }
+            Module::Conj {
+                name: _,
+                input_states,
+            } => {
+                assert!(input_states.contains_key(sender)); // this is guaranteed from parsing
+                input_states.insert(sender.to_owned(), received_pulse);
+                match input_states.iter().all(|(_, &pulse)| pulse == Pulse::Hi) {
+                    true => Some(Pulse::Lo),
+                    false => Some(Pulse::Hi),
+                }
+            }
+        }
+    }
+}
+
+/// Module prefix, name and output names, the broadcaster gets the prefix `b`.
+type Row<'a> = (char, &'a str, Vec<&'a str>);
+
+/// `%a -> b, c`
+fn row<'a>() -> impl Fn(Input<'a>) -> PResult<'a, Spanned<Row<'a>>> {
+    let module = or(
+        map(tag("broadcaster"), |name| ('b', name)),
+        pair(one_of([("%", '%'), ("&", '&')]), word()),
+    );
+    let outputs = list(word(), tag(", "));
+    return spanned(map(
+        pair(terminated(module, tag(" -> ")), outputs),
+        |((prefix, name), out_names)| (prefix, name, out_names),
+    ));
+}
+
+// module name -> names of the modules it sends to
+type Transition = HashMap<String, Vec<String>>;
+
+fn parse(content: &str) -> Result<(HashMap<String, Module>, Transition), Error> {
+    // broadcaster -> a, b, c
+    // %a -> b
+    // %b -> c
+    // %c -> inv
+    // &inv -> a
+
+    // the parsing is a bit unique because we must know both right and left side first
+    let mut in2outs: Transition = HashMap::new();
+    let mut out2ins: HashMap<String, Vec<String>> = HashMap::new();
+    let mut modules = HashMap::new();
+    for Spanned { value, span } in parse_all(content, lines(row()))? {
+        let (prefix, name, out_names) = value;
+        let name = name.to_owned();
+        let module = match prefix {
+            'b' => Module::Broad { name: name.clone() },
+            '%' => Module::Flip {
+                name: name.clone(),
+                state: Pulse::Lo,
+            },
+            _ => Module::Conj {
+                name: name.clone(),
+                input_states: HashMap::new(),
+            },
+        };
+        if modules.insert(name.clone(), module).is_some() {
+            let msg = format!("duplicate module: {name:?}");
+            return Err(error_at(content, span.start, msg));
+        }
+        for out_name in out_names {
+            in2outs
+                .entry(name.clone())
+                .or_default()
+                .push(out_name.to_owned());
+            out2ins
+                .entry(out_name.to_owned())
+                .or_default()
+                .push(name.clone());
+        }
+    }
+    if !modules.contains_key("broadcaster") {
+        return Err(Error::parse("there is no broadcaster"));
+    }
+    // just for conj module, we need to keep track the input names
+    for (_, module) in modules.iter_mut() {
+        if let Module::Conj {
+            name, input_states, ..
+        } = module
+        {
+            for out_name in out2ins.get(name).into_iter().flatten() {
+                input_states.insert(out_name.clone(), Pulse::Lo);
+            }
+        }
+    }
+    return Ok((modules, in2outs));
+}
+
+/// Parse without solving, for the fuzz targets.
+pub fn validate(content: &str) -> Result<(), Error> {
+    return parse(content).map(|_| ());
+}
+
+fn rx_received_low_pulse(
+    modules: &mut HashMap<String, Module>,
+    transition: &HashMap<String, Vec<String>>,
+) -> bool {
+    let mut queue = VecDeque::<(&String, Pulse, &String)>::new();
+    // first broadcast node
+    let b = &String::from("broadcaster");
+    for next in transition.get("broadcaster").unwrap() {
+        queue.push_back((b, Pulse::Lo, next));
+    }
+    while !queue.is_empty() {
+        let (prev, pulse, curr) = queue.pop_front().unwrap();
+        // special case "rx" module
+        if curr == "rx" {
+            if pulse == Pulse::Lo {
+                return true;
+            } else {
+                continue;
+            }
+        }
+        // special case for untyped module, thus cannot produce pulse
+        let Some(curr_module) = modules.get_mut(curr) else {
+            continue;
+        };
+        // curr produce pulse (or not!)
+        if let Some(curr_pulse) = curr_module.send_pulse(prev, pulse) {
+            for next in transition.get(curr).unwrap() {
+                queue.push_back((curr, curr_pulse, next));
+            }
+        }
+    }
+    false
+}
+
+/// The parsed modules and where each one sends its pulses, sorted by name.
+fn dump_network(modules: &HashMap<String, Module>, transition: &Transition) {
+    if !dump::enabled(Level::Debug) {
+        return;
+    }
+    dump!(Debug, "MODULES");
+    for (k, v) in modules.iter().sorted_by_key(|(k, _)| *k) {
+        dump!(Debug, "{k:?} {v:?}");
+    }
+    dump!(Debug, "TRANSITION");
+    for (k, v) in transition.iter().sorted_by_key(|(k, _)| *k) {
+        dump!(Debug, "{k} {v:?}");
+    }
+}
+
+fn solve(content: &str, progress: &Progress) -> Result<u64, Error> {
+    let (mut modules, transition) = parse(content)?;
+    // without this the loop below never ends
+    if !transition
+        .values()
+        .flatten()
+        .any(|out_name| out_name == "rx")
+    {
+        return Err(Error::parse("nothing sends to rx"));
+    }
+    dump_network(&modules, &transition);
+    let mut n_simulations = 0;
+    loop {
+        n_simulations += 1;
+        // brute force, on real inputs this runs until it is cancelled
+        progress.tick(n_simulations)?;
+        let rx_hit = rx_received_low_pulse(&mut modules, &transition);
+        if rx_hit {
+            break;
+        }
+    }
+    return Ok(n_simulations);
+}
+
+pub fn run(content: &str, progress: &Progress) -> Result<Answer, Error> {
+    let result = solve(content, progress)?;
+    return Ok(i128::from(result).into());
+}
+
+pub fn inspect(content: &str) -> Result<Facts, Error> {
+    let (modules, transition) = parse(content)?;
+    let count = |kind: fn(&Module) -> bool| modules.values().filter(|m| kind(m)).count();
+    let feeders = transition
+        .iter()
+        .filter(|(_, next)| next.iter().any(|name| name == "rx"))
+        .map(|(name, _)| name.as_str())
+        .sorted()
+        .collect::<Vec<_>>();
+    // rx only ever gets a low pulse once every input of the conjunction before it is high
+    let fan_in = feeders
+        .iter()
+        .map(|name| match modules.get(*name) {
+            Some(Module::Conj { input_states, .. }) => input_states.len(),
+            _ => 1,
+        })
+        .sum::<usize>();
+    let mut facts = Facts::new();
+    facts.note("modules", modules.len());
+    facts.note("flip-flops", count(|m| matches!(m, Module::Flip { .. })));
+    facts.note("conjunctions", count(|m| matches!(m, Module::Conj { .. })));
+    facts.note("feeding rx", feeders.join(", "));
+    facts.note("fan-in to rx", fan_in);
+    return Ok(facts);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rx() {
+        let content = String::from(
+            "broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> rx",
+        );
+        let result = solve(&content, &Progress::new()).unwrap();
+        assert_eq!(result, 1);
+
+        let facts = inspect(&content).unwrap();
+        assert_eq!(facts.get("flip-flops"), Some("2"));
+        assert_eq!(facts.get("conjunctions"), Some("2"));
+        assert_eq!(facts.get("feeding rx"), Some("con"));
+        assert_eq!(facts.get("fan-in to rx"), Some("2"));
+    }
+
+    #[test]
+    fn test_cancelled() {
+        // rx only ever gets high pulses
+        let content = "broadcaster -> a\n&a -> rx";
+        let progress = Progress::new();
+        progress.cancel();
+        assert_eq!(solve(content, &progress), Err(Error::Cancelled));
+    }
+
+    #[test]
+    fn test_bad_input() {
+        for content in [
+            "%a -> b",
+            "broadcaster a",
+            "broadcast -> a",
+            "broadcaster -> a\n% -> a",
+            "broadcaster -> a\n#a -> b",
+            "broadcaster -> a\n%a -> ",
+            "broadcaster -> a\n%a -> b\n&a -> b",
+        ] {
+            assert!(validate(content).is_err(), "{content:?}");
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::HashSet;
+
+use crate::{geometry::Pos, params::Param, Answer, Error, Facts, Grid, Params};
+
+#[derive(Debug, PartialEq, Eq)]
+enum Field {
+    Garden,
+    Rock,
+}
+
+fn parse(content: &str) -> Result<(Grid<Field>, Pos), Error> {
+    let area = Grid::parse_bytes(content.as_bytes(), |ch| match ch {
+        b'#' | b'.' | b'S' => Some(ch),
+        _ => None,
+    })?;
+    let start = area
+        .find(&b'S')
+        .ok_or_else(|| Error::parse("no starting position S"))?;
+    // S is a regular garden '.'
+    let area = area.map(|&ch| match ch {
+        b'#' => Field::Rock,
+        _ => Field::Garden,
+    });
+    return Ok((area, start));
+}
+
+pub fn validate(content: &str) -> Result<(), Error> {
+    return parse(content).map(|_| ());
+}
+
+fn mark(area: &Grid<Field>, steps: i32, start: Pos) -> usize {
+    let bounds = area.bounds();
+    // the idea: instead of using dfs, better to keep expanding out from the marked area
+    let mut positions = HashSet::new();
+    positions.insert(start);
+    for _ in 0..steps {
+        let mut new_pos = HashSet::new();
+        for pos in positions {
+            for (_, next) in bounds.neighbours(pos) {
+                if area[next] == Field::Rock {
+                    continue;
+                }
+                new_pos.insert(next);
+            }
+        }
+        positions = new_pos;
+    }
+    positions.len()
+}
+
+fn solve(content: &str, steps: i32) -> Result<i32, Error> {
+    let (area, start) = parse(content)?;
+    return Ok(mark(&area, steps, start) as i32);
+}
+
+pub const PARAMS: &[Param] = &[Param {
+    name: "steps",
+    default: 64,
+    min: 0,
+    max: i64::MAX,
+    help: "steps the elf takes",
+}];
+
+pub fn run(content: &str, params: &Params) -> Result<Answer, Error> {
+    let result = solve(content, params.get("steps")?)?;
+    return Ok(result.into());
+}
+
+pub fn inspect(content: &str) -> Result<Facts, Error> {
+    let (area, start) = parse(content)?;
+    let middle = Pos::new(area.h() as i64 / 2, area.w() as i64 / 2);
+    let mut facts = Facts::new();
+    facts.note("rows", area.h());
+    facts.note("columns", area.w());
+    facts.note("start", format!("{},{}", start.y, start.x));
+    facts.note("start in the middle", start == middle);
+    facts.note(
+        "rocks",
+        area.iter().filter(|(_, f)| **f == Field::Rock).count(),
+    );
+    return Ok(facts);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let content = String::from(
+            "...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........",
+        );
+        let result = solve(&content, 6).unwrap();
+        assert_eq!(result, 16);
+    }
+}
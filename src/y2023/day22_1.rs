@@ -1,4 +1,6 @@
-use std::{cmp::max, fs};
+use std::cmp::max;
+
+use crate::{dump, dump::Level, Answer, Error, Facts};
 
 #[derive(Debug)]
 struct Brick {
@@ -30,9 +32,12 @@ struct Wall {
     bricks: Vec<Brick>,
 }
 impl Wall {
-    fn print(&self) {
-        for brick in &self.bricks {
-            println!("{brick:?}");
+    fn dump(&self, when: &str) {
+        if dump::enabled(Level::Debug) {
+            dump!(Debug, "bricks {when}");
+            for brick in &self.bricks {
+                dump!(Debug, "{brick:?}");
+            }
         }
     }
     fn sort_z(&mut self) {
@@ -79,7 +84,7 @@ impl Wall {
     }
 }
 
-fn parse(content: &String) -> Wall {
+fn parse(content: &str) -> Wall {
     let bricks = content
         .lines()
         .map(|line| {
@@ -107,13 +112,12 @@ fn parse(content: &String) -> Wall {
     Wall { bricks }
 }
 
-fn solve(content: &String) -> i32 {
+fn solve(content: &str) -> i32 {
     let mut wall = parse(content);
     wall.sort_z();
-    // wall.print();
-    // println!();
+    wall.dump("in the air");
     wall.drop_all();
-    // wall.print();
+    wall.dump("settled");
     let mut safe_to_destroy = 0;
     for i_destroy in 0..wall.bricks.len() {
         if wall.is_safe_destroyed(i_destroy) {
@@ -123,10 +127,21 @@ fn solve(content: &String) -> i32 {
     safe_to_destroy
 }
 
-fn main() {
-    let content = fs::read_to_string("inputs/day22.txt").expect("input for day 22 is missing");
-    let result = solve(&content);
-    println!("day 22 part 1: {result}");
+pub fn run(content: &str) -> Result<Answer, Error> {
+    let result = solve(content);
+    return Ok(result.into());
+}
+
+pub fn inspect(content: &str) -> Result<Facts, Error> {
+    let mut wall = parse(content);
+    let height = |wall: &Wall| wall.bricks.iter().map(|brick| brick.z1).max().unwrap_or(0);
+    let mut facts = Facts::new();
+    facts.note("bricks", wall.bricks.len());
+    facts.note("highest brick", height(&wall));
+    wall.sort_z();
+    wall.drop_all();
+    facts.note("settled height", height(&wall));
+    return Ok(facts);
 }
 
 #[cfg(test)]
@@ -1,7 +1,9 @@
-use std::{cmp::max, fs};
+use std::cmp::max;
 
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
+use crate::{dump, dump::Level, registry::Algo, Answer, Error};
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 struct Brick {
     x0: i32,
@@ -33,9 +35,12 @@ struct Wall {
     bricks: Vec<Brick>,
 }
 impl Wall {
-    fn print(&self) {
-        for brick in &self.bricks {
-            println!("{brick:?}");
+    fn dump(&self, when: &str) {
+        if dump::enabled(Level::Debug) {
+            dump!(Debug, "bricks {when}");
+            for brick in &self.bricks {
+                dump!(Debug, "{brick:?}");
+            }
         }
     }
     fn sort_z(&mut self) {
@@ -87,7 +92,7 @@ impl Wall {
     }
 }
 
-fn parse(content: &String) -> Wall {
+fn parse(content: &str) -> Wall {
     let bricks = content
         .lines()
         .map(|line| {
@@ -115,44 +120,53 @@ fn parse(content: &String) -> Wall {
     Wall { bricks }
 }
 
-fn solve(content: &String) -> i32 {
+/// How many other bricks fall once brick `i` is gone.
+fn chain_reaction(wall: &Wall, i: usize) -> i32 {
+    let mut experiment = wall.clone();
+    experiment.drop_all_ignore(i);
+    experiment.count_differences(wall)
+}
+
+fn settle(content: &str) -> Wall {
     let mut wall = parse(content);
     wall.sort_z();
-    // wall.print();
-    // println!();
+    wall.dump("in the air");
     wall.drop_all();
-    // wall.print();
-
-    // VIRGIN SINGLE CORE
-    // (0..wall.bricks.len())
-    //     .into_iter()
-    //     .map(|i| {
-    //         let mut experiment = wall.clone();
-    //         experiment.drop_all_ignore(i);
-    //         experiment.count_differences(&wall)
-    //     })
-    //     .sum()
+    wall.dump("settled");
+    wall
+}
 
+fn solve(content: &str) -> i32 {
+    let wall = settle(content);
     // CHAD MULTI CORE RAYON
     (0..wall.bricks.len())
         .into_par_iter()
-        .map(|i| {
-            let mut experiment = wall.clone();
-            experiment.drop_all_ignore(i);
-            experiment.count_differences(&wall)
-        })
+        .map(|i| chain_reaction(&wall, i))
         .sum()
 }
 
-fn main() {
-    let content = fs::read_to_string("inputs/day22.txt").expect("input for day 22 is missing");
-    let result = solve(&content);
-    println!("day 22 part 2: {result}");
+fn solve_single_core(content: &str) -> i32 {
+    let wall = settle(content);
+    (0..wall.bricks.len())
+        .map(|i| chain_reaction(&wall, i))
+        .sum()
+}
+
+pub fn run(content: &str) -> Result<Answer, Error> {
+    let result = solve(content);
+    return Ok(result.into());
 }
 
+pub const ALGOS: &[Algo] = &[Algo {
+    name: "single-core",
+    help: "the same chain reactions one after another instead of on every core",
+    run: |content, _, _, _| Ok(solve_single_core(content).into()),
+}];
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{registry, Params};
 
     #[test]
     fn test() {
@@ -167,5 +181,9 @@ mod tests {
         );
         let result = solve(&content);
         assert_eq!(result, 7);
+        assert_eq!(
+            registry::assert_algos_agree(2023, 22, 2, &content, &Params::new()),
+            Answer::Int(7)
+        );
     }
 }
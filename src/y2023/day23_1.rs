@@ -0,0 +1,284 @@
+use std::{cmp, collections::HashSet};
+
+use crate::{
+    certificate::{self, Certificate},
+    geometry::{Dir, Pos},
+    Answer, Error, Grid,
+};
+
+/// The tiles of the hike so far and the longest hike that reached the target.
+#[derive(Default)]
+struct Hike {
+    visited: HashSet<Pos>,
+    route: Vec<Pos>,
+    longest: Vec<Pos>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Block {
+    Forest,
+    Path,
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Block {
+    fn dir(&self) -> Vec<Dir> {
+        match self {
+            Block::Forest => vec![],
+            Block::Path => Dir::ALL.to_vec(),
+            Block::Up => vec![Dir::Up],
+            Block::Down => vec![Dir::Down],
+            Block::Left => vec![Dir::Left],
+            Block::Right => vec![Dir::Right],
+        }
+    }
+}
+
+fn parse(content: &str) -> Result<Grid<Block>, Error> {
+    return Grid::parse_bytes(content.as_bytes(), |ch| match ch {
+        b'#' => Some(Block::Forest),
+        b'.' => Some(Block::Path),
+        b'^' => Some(Block::Up),
+        b'v' => Some(Block::Down),
+        b'<' => Some(Block::Left),
+        b'>' => Some(Block::Right),
+        _ => None,
+    });
+}
+
+pub fn validate(content: &str) -> Result<(), Error> {
+    return parse(content).map(|_| ());
+}
+
+fn walk(map: &Grid<Block>, tgt_coord: Pos, start: Pos, hike: &mut Hike) -> Option<i32> {
+    let mut longest = None;
+    // directions tried so far on each tile of the route, the call stack of a recursive walk
+    // that would overflow on a long trail
+    let mut tried = vec![];
+    let mut next = Some(start);
+    loop {
+        if let Some(coord) = next.take() {
+            match map.get(coord) {
+                None | Some(Block::Forest) => {}
+                Some(_) if tgt_coord == coord => {
+                    if hike.route.len() >= hike.longest.len() {
+                        hike.longest = hike.route.clone();
+                        hike.longest.push(coord);
+                    }
+                    let depth = hike.route.len() as i32;
+                    longest = Some(longest.map_or(depth, |longest| cmp::max(longest, depth)));
+                }
+                Some(_) if hike.visited.contains(&coord) => {}
+                Some(_) => {
+                    hike.visited.insert(coord);
+                    hike.route.push(coord);
+                    tried.push(0);
+                }
+            }
+        }
+        let (Some(&coord), Some(tried_here)) = (hike.route.last(), tried.last_mut()) else {
+            break;
+        };
+        match map[coord].dir().get(*tried_here) {
+            Some(&dir) => {
+                *tried_here += 1;
+                next = Some(coord.step(dir));
+            }
+            // back to the previous tile
+            None => {
+                hike.visited.remove(&coord);
+                hike.route.pop();
+                tried.pop();
+            }
+        }
+    }
+    longest
+}
+
+/// The longest hike and its tiles, both ends included.
+fn walk_longest(content: &str) -> Result<(i32, Vec<Pos>), Error> {
+    let map = parse(content)?;
+    let target = Pos::new(map.h() as i64 - 1, map.w() as i64 - 2);
+    let start = Pos::new(0, 1);
+    let mut hike = Hike::default();
+    let longest = walk(&map, target, start, &mut hike);
+    let longest = longest.ok_or_else(|| Error::parse("no hike reaches the target"))?;
+    return Ok((longest, hike.longest));
+}
+
+fn solve(content: &str) -> Result<i32, Error> {
+    let (longest, _) = walk_longest(content)?;
+    return Ok(longest);
+}
+
+pub fn run(content: &str) -> Result<Answer, Error> {
+    let result = solve(content)?;
+    return Ok(result.into());
+}
+
+pub fn certify(content: &str) -> Result<(Answer, Certificate), Error> {
+    let (result, route) = walk_longest(content)?;
+    return Ok((result.into(), Certificate::Path(route)));
+}
+
+/// The path goes from the start to the target one tile at a time, only down the slopes, never through the
+/// forest and never twice over the same tile, in as many steps as the answer says.
+pub fn check(content: &str, answer: &Answer, certificate: &Certificate) -> Result<(), Error> {
+    let map = parse(content)?;
+    let Certificate::Path(path) = certificate else {
+        return Err(Error::rejected("not a path"));
+    };
+    let target = Pos::new(map.h() as i64 - 1, map.w() as i64 - 2);
+    if path.first() != Some(&Pos::new(0, 1)) || path.last() != Some(&target) {
+        return Err(Error::rejected(
+            "it does not go from the start to the target",
+        ));
+    }
+    let dirs = certificate::directions(path)?;
+    let mut seen = HashSet::new();
+    // the target is the only tile the path does not leave
+    for (&pos, dir) in path.iter().zip(dirs.into_iter().map(Some).chain([None])) {
+        let at = format!("{},{}", pos.y, pos.x);
+        match map.get(pos) {
+            None => return Err(Error::rejected(format!("it leaves the map at {at}"))),
+            Some(Block::Forest) => {
+                return Err(Error::rejected(format!("it enters the forest at {at}")))
+            }
+            Some(block) if dir.is_some_and(|dir| !block.dir().contains(&dir)) => {
+                return Err(Error::rejected(format!("it climbs the slope at {at}")));
+            }
+            Some(_) => {}
+        }
+        if !seen.insert(pos) {
+            return Err(Error::rejected(format!("it steps on {at} twice")));
+        }
+    }
+    return certificate::claims(answer, path.len() as i64 - 1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_route() {
+        // the trail from the start ends in the forest
+        let content = "#.###\n#.#.#\n###.#";
+        assert_eq!(
+            solve(content),
+            Err(Error::parse("no hike reaches the target"))
+        );
+        assert_eq!(
+            certify(content).map(|_| ()),
+            Err(Error::parse("no hike reaches the target"))
+        );
+    }
+
+    #[test]
+    fn test() {
+        let content = String::from(
+            "#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#",
+        );
+        let result = solve(&content).unwrap();
+        assert_eq!(result, 94);
+
+        let (answer, certificate) = certify(&content).unwrap();
+        assert_eq!(check(&content, &answer, &certificate), Ok(()));
+        assert!(check(&content, &Answer::Int(95), &certificate).is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let path = |cells: &[(i64, i64)]| {
+            Certificate::Path(cells.iter().map(|&(y, x)| Pos::new(y, x)).collect())
+        };
+        let content = "#.###\n#.>.#\n#.#.#\n#...#\n###.#";
+        let good = path(&[(0, 1), (1, 1), (1, 2), (1, 3), (2, 3), (3, 3), (4, 3)]);
+        assert_eq!(check(content, &Answer::Int(6), &good), Ok(()));
+        for (bad, why) in [
+            (
+                path(&[(0, 1), (1, 1), (1, 2), (1, 3), (2, 3), (3, 3)]),
+                "it does not go from the start to the target",
+            ),
+            (
+                path(&[(0, 1), (1, 1), (2, 1), (2, 2), (2, 3), (3, 3), (4, 3)]),
+                "it enters the forest at 2,2",
+            ),
+            (
+                path(&[
+                    (0, 1),
+                    (1, 1),
+                    (1, 2),
+                    (1, 3),
+                    (2, 3),
+                    (3, 3),
+                    (3, 2),
+                    (3, 3),
+                    (4, 3),
+                ]),
+                "it steps on 3,3 twice",
+            ),
+        ] {
+            assert_eq!(
+                check(content, &Answer::Int(6), &bad),
+                Err(Error::rejected(why))
+            );
+        }
+
+        let uphill = "#.###\n#.#.#\n#.<.#\n###.#";
+        let climb = path(&[(0, 1), (1, 1), (2, 1), (2, 2), (2, 3), (3, 3)]);
+        assert_eq!(
+            check(uphill, &Answer::Int(5), &climb),
+            Err(Error::rejected("it climbs the slope at 2,2"))
+        );
+    }
+
+    /// One trail winding through `corridors` rows of the map, an odd number of them to end
+    /// up on the right. Every tile of the corridors is on the hike.
+    fn snake(width: usize, corridors: usize) -> String {
+        let wall = |gap: usize| {
+            let mut row = vec![b'#'; width];
+            row[gap] = b'.';
+            return String::from_utf8(row).unwrap();
+        };
+        let mut rows = vec![wall(1)];
+        for i in 0..corridors {
+            rows.push(format!("#{}#", ".".repeat(width - 2)));
+            rows.push(wall(if i % 2 == 0 { width - 2 } else { 1 }));
+        }
+        return rows.join("\n");
+    }
+
+    #[test]
+    fn test_long_hike() {
+        // down between the corridors, across each of them
+        let (width, corridors) = (1002, 201);
+        let expected = 2 * corridors + corridors * (width - 3);
+        assert_eq!(solve(&snake(width, corridors)).unwrap(), expected as i32);
+    }
+}
@@ -0,0 +1,390 @@
+use std::{
+    cmp,
+    collections::{HashMap, HashSet},
+};
+
+use crate::{
+    certificate::{self, Certificate},
+    geometry::{Dir, Pos},
+    registry::Algo,
+    Answer, Error, Facts, Grid, Progress,
+};
+
+/// Visited tiles between two progress ticks.
+const TICK_EVERY: u64 = 1 << 16;
+
+/// The tiles of the hike so far and the longest hike that reached the target.
+struct Hike {
+    visited: Grid<bool>,
+    route: Vec<Pos>,
+    longest: Vec<Pos>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Block {
+    Forest,
+    Path,
+}
+
+impl Block {
+    fn dir(&self) -> Vec<Dir> {
+        match self {
+            Block::Forest => vec![],
+            Block::Path => Dir::ALL.to_vec(),
+        }
+    }
+}
+
+fn parse(content: &str) -> Result<Grid<Block>, Error> {
+    return Grid::parse_bytes(content.as_bytes(), |ch| match ch {
+        b'#' => Some(Block::Forest),
+        b'.' => Some(Block::Path),
+        b'^' => Some(Block::Path),
+        b'v' => Some(Block::Path),
+        b'<' => Some(Block::Path),
+        b'>' => Some(Block::Path),
+        _ => None,
+    });
+}
+
+pub fn validate(content: &str) -> Result<(), Error> {
+    return parse(content).map(|_| ());
+}
+
+fn walk(
+    map: &Grid<Block>,
+    tgt_coord: Pos,
+    start: Pos,
+    hike: &mut Hike,
+    progress: &Progress,
+) -> Result<Option<i32>, Error> {
+    let mut longest = None;
+    let mut steps: u64 = 0;
+    // directions tried so far on each tile of the route, the call stack of a recursive walk
+    // that would overflow on a long trail
+    let mut tried = vec![];
+    let mut next = Some(start);
+    loop {
+        if let Some(coord) = next.take() {
+            if steps.is_multiple_of(TICK_EVERY) {
+                progress.tick(steps)?;
+            }
+            steps += 1;
+            match map.get(coord) {
+                None | Some(Block::Forest) => {}
+                Some(_) if tgt_coord == coord => {
+                    if hike.route.len() >= hike.longest.len() {
+                        hike.longest = hike.route.clone();
+                        hike.longest.push(coord);
+                    }
+                    let depth = hike.route.len() as i32;
+                    longest = Some(longest.map_or(depth, |longest| cmp::max(longest, depth)));
+                }
+                Some(_) if hike.visited[coord] => {}
+                Some(_) => {
+                    hike.visited[coord] = true;
+                    hike.route.push(coord);
+                    tried.push(0);
+                }
+            }
+        }
+        let (Some(&coord), Some(tried_here)) = (hike.route.last(), tried.last_mut()) else {
+            break;
+        };
+        match map[coord].dir().get(*tried_here) {
+            Some(&dir) => {
+                *tried_here += 1;
+                next = Some(coord.step(dir));
+            }
+            // back to the previous tile
+            None => {
+                hike.visited[coord] = false;
+                hike.route.pop();
+                tried.pop();
+            }
+        }
+    }
+    return Ok(longest);
+}
+
+/// The longest hike and its tiles, both ends included.
+fn walk_longest(content: &str, progress: &Progress) -> Result<(i32, Vec<Pos>), Error> {
+    let map = parse(content)?;
+    let target = Pos::new(map.h() as i64 - 1, map.w() as i64 - 2);
+    let start = Pos::new(0, 1);
+    let mut hike = Hike {
+        visited: Grid::new(map.h(), map.w(), false),
+        route: vec![],
+        longest: vec![],
+    };
+    let longest = walk(&map, target, start, &mut hike, progress)?;
+    let longest = longest.ok_or_else(|| Error::parse("no hike reaches the target"))?;
+    return Ok((longest, hike.longest));
+}
+
+fn solve(content: &str, progress: &Progress) -> Result<i32, Error> {
+    let (longest, _) = walk_longest(content, progress)?;
+    return Ok(longest);
+}
+
+pub fn run(content: &str, progress: &Progress) -> Result<Answer, Error> {
+    let result = solve(content, progress)?;
+    return Ok(result.into());
+}
+
+pub fn inspect(content: &str) -> Result<Facts, Error> {
+    let map = parse(content)?;
+    let trails = compress(&map);
+    let corridors = trails.edges.iter().flatten();
+    let mut facts = Facts::new();
+    facts.note("rows", map.h());
+    facts.note("columns", map.w());
+    // the start and the target are nodes too, but dead ends
+    facts.note("junctions", trails.edges.len() - 2);
+    facts.note("corridors", corridors.clone().count() / 2);
+    facts.note(
+        "longest corridor",
+        corridors.map(|&(_, len)| len).max().unwrap_or(0),
+    );
+    return Ok(facts);
+}
+
+/// The map as a graph of the start, the target and the junctions, joined by the lengths of
+/// the corridors between them.
+struct Trails {
+    edges: Vec<Vec<(usize, i32)>>,
+    start: usize,
+    target: usize,
+}
+
+fn is_path(map: &Grid<Block>, pos: Pos) -> bool {
+    return map.get(pos) == Some(&Block::Path);
+}
+
+fn compress(map: &Grid<Block>) -> Trails {
+    let start = Pos::new(0, 1);
+    let target = Pos::new(map.h() as i64 - 1, map.w() as i64 - 2);
+    let mut nodes = vec![start, target];
+    for y in 0..map.h() as i64 {
+        for x in 0..map.w() as i64 {
+            let pos = Pos::new(y, x);
+            let exits = Dir::ALL
+                .iter()
+                .filter(|&&dir| is_path(map, pos.step(dir)))
+                .count();
+            if is_path(map, pos) && exits > 2 {
+                nodes.push(pos);
+            }
+        }
+    }
+    let index = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, &pos)| (pos, i))
+        .collect::<HashMap<_, _>>();
+    let mut edges = vec![vec![]; nodes.len()];
+    for (i, &node) in nodes.iter().enumerate() {
+        for dir in Dir::ALL {
+            // follow the corridor until the next node, it has no forks on the way
+            let (mut prev, mut pos, mut len) = (node, node.step(dir), 1);
+            if !is_path(map, pos) {
+                continue;
+            }
+            while !index.contains_key(&pos) {
+                let next = Dir::ALL
+                    .into_iter()
+                    .map(|dir| pos.step(dir))
+                    .find(|&next| next != prev && is_path(map, next));
+                let Some(next) = next else {
+                    break;
+                };
+                (prev, pos, len) = (pos, next, len + 1);
+            }
+            if let Some(&j) = index.get(&pos) {
+                edges[i].push((j, len));
+            }
+        }
+    }
+    return Trails {
+        edges,
+        start: 0,
+        target: 1,
+    };
+}
+
+fn walk_trails(
+    trails: &Trails,
+    node: usize,
+    visited: &mut Vec<bool>,
+    steps: &mut u64,
+    progress: &Progress,
+) -> Result<Option<i32>, Error> {
+    progress.tick(*steps)?;
+    *steps += 1;
+    if node == trails.target {
+        return Ok(Some(0));
+    }
+    visited[node] = true;
+    let mut longest = None;
+    for &(next, len) in &trails.edges[node] {
+        if visited[next] {
+            continue;
+        }
+        if let Some(rest) = walk_trails(trails, next, visited, steps, progress)? {
+            longest = cmp::max(longest, Some(len + rest));
+        }
+    }
+    visited[node] = false;
+    return Ok(longest);
+}
+
+fn solve_graph(content: &str, progress: &Progress) -> Result<i32, Error> {
+    let map = parse(content)?;
+    let trails = compress(&map);
+    let mut visited = vec![false; trails.edges.len()];
+    let longest = walk_trails(&trails, trails.start, &mut visited, &mut 0, progress)?;
+    return longest.ok_or_else(|| Error::parse("no hike reaches the target"));
+}
+
+pub const ALGOS: &[Algo] = &[Algo {
+    name: "graph",
+    help: "the same search over the junctions only, skipping the corridors between them",
+    run: |content, _, _, progress| Ok(solve_graph(content, progress)?.into()),
+}];
+
+pub fn certify(content: &str) -> Result<(Answer, Certificate), Error> {
+    let (result, route) = walk_longest(content, &Progress::new())?;
+    return Ok((result.into(), Certificate::Path(route)));
+}
+
+/// The path goes from the start to the target one tile at a time, never through the forest
+/// and never twice over the same tile, in as many steps as the answer says.
+pub fn check(content: &str, answer: &Answer, certificate: &Certificate) -> Result<(), Error> {
+    let map = parse(content)?;
+    let Certificate::Path(path) = certificate else {
+        return Err(Error::rejected("not a path"));
+    };
+    let target = Pos::new(map.h() as i64 - 1, map.w() as i64 - 2);
+    if path.first() != Some(&Pos::new(0, 1)) || path.last() != Some(&target) {
+        return Err(Error::rejected(
+            "it does not go from the start to the target",
+        ));
+    }
+    certificate::directions(path)?;
+    let mut seen = HashSet::new();
+    for &pos in path {
+        let at = format!("{},{}", pos.y, pos.x);
+        match map.get(pos) {
+            None => return Err(Error::rejected(format!("it leaves the map at {at}"))),
+            Some(Block::Forest) => {
+                return Err(Error::rejected(format!("it enters the forest at {at}")));
+            }
+            Some(Block::Path) => {}
+        }
+        if !seen.insert(pos) {
+            return Err(Error::rejected(format!("it steps on {at} twice")));
+        }
+    }
+    return certificate::claims(answer, path.len() as i64 - 1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{registry, Params};
+
+    #[test]
+    fn test_no_route() {
+        // the trail from the start ends in the forest
+        let content = "#.###\n#.#.#\n###.#";
+        assert_eq!(
+            solve(content, &Progress::new()),
+            Err(Error::parse("no hike reaches the target"))
+        );
+        assert_eq!(
+            solve_graph(content, &Progress::new()),
+            Err(Error::parse("no hike reaches the target"))
+        );
+    }
+
+    #[test]
+    fn test() {
+        let content = String::from(
+            "#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#",
+        );
+        let result = solve(&content, &Progress::new()).unwrap();
+        assert_eq!(result, 154);
+        assert_eq!(solve_graph(&content, &Progress::new()), Ok(154));
+        let facts = inspect(&content).unwrap();
+        assert_eq!(facts.get("rows"), Some("23"));
+        assert_eq!(facts.get("junctions"), Some("7"));
+        assert_eq!(facts.get("corridors"), Some("12"));
+        assert_eq!(
+            registry::assert_algos_agree(2023, 23, 2, &content, &Params::new()),
+            Answer::Int(154)
+        );
+
+        let cancelled = Progress::new();
+        cancelled.cancel();
+        assert_eq!(solve(&content, &cancelled), Err(Error::Cancelled));
+
+        let (answer, certificate) = certify(&content).unwrap();
+        assert_eq!(check(&content, &answer, &certificate), Ok(()));
+        // the longest hike of part 1 is a valid hike, just not as long
+        let (_, shorter) = crate::y2023::day23_1::certify(&content).unwrap();
+        assert_eq!(
+            check(&content, &answer, &shorter),
+            Err(Error::rejected("it adds up to 94, not 154"))
+        );
+        assert_eq!(check(&content, &Answer::Int(94), &shorter), Ok(()));
+    }
+
+    /// One trail winding through `corridors` rows of the map, an odd number of them to end
+    /// up on the right. Every tile of the corridors is on the hike.
+    fn snake(width: usize, corridors: usize) -> String {
+        let wall = |gap: usize| {
+            let mut row = vec![b'#'; width];
+            row[gap] = b'.';
+            return String::from_utf8(row).unwrap();
+        };
+        let mut rows = vec![wall(1)];
+        for i in 0..corridors {
+            rows.push(format!("#{}#", ".".repeat(width - 2)));
+            rows.push(wall(if i % 2 == 0 { width - 2 } else { 1 }));
+        }
+        return rows.join("\n");
+    }
+
+    #[test]
+    fn test_long_hike() {
+        // down between the corridors, across each of them
+        let (width, corridors) = (1002, 201);
+        let expected = 2 * corridors + corridors * (width - 3);
+        assert_eq!(
+            solve(&snake(width, corridors), &Progress::new()).unwrap(),
+            expected as i32
+        );
+    }
+}
@@ -0,0 +1,49 @@
+//! Advent of Code 2023, one module per day and part.
+
+pub const YEAR: u32 = 2023;
+
+pub mod day01_1;
+pub mod day01_2;
+pub mod day02_1;
+pub mod day02_2;
+pub mod day03_1;
+pub mod day03_2;
+pub mod day04_1;
+pub mod day04_2;
+pub mod day05_1;
+pub mod day05_2;
+pub mod day06_1;
+pub mod day06_2;
+pub mod day07_1;
+pub mod day07_2;
+pub mod day08_1;
+pub mod day08_2;
+pub mod day09_1;
+pub mod day09_2;
+pub mod day10_1;
+pub mod day10_2;
+pub mod day11_1;
+pub mod day11_2;
+pub mod day12_1;
+pub mod day12_2;
+pub mod day13_1;
+pub mod day13_2;
+pub mod day14_1;
+pub mod day14_2;
+pub mod day15_1;
+pub mod day15_2;
+pub mod day16_1;
+pub mod day16_2;
+pub mod day17_1;
+pub mod day17_2;
+pub mod day18_1;
+pub mod day18_2;
+pub mod day19_1;
+pub mod day19_2;
+pub mod day20_1;
+pub mod day20_2;
+pub mod day21_1;
+pub mod day22_1;
+pub mod day22_2;
+pub mod day23_1;
+pub mod day23_2;
@@ -0,0 +1,46 @@
+use aoc23::{explain, solve, Answer, Error};
+
+#[test]
+fn test_solve_example() {
+    let content = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+    assert_eq!(solve(2023, 7, 1, content), Ok(Answer::Int(6440)));
+    assert_eq!(solve(2023, 7, 2, content), Ok(Answer::Int(5905)));
+}
+
+#[test]
+fn test_no_solver() {
+    let no_solver = |year, day, part| Err(Error::NoSolver { year, day, part });
+    assert_eq!(solve(2023, 25, 1, ""), no_solver(2023, 25, 1));
+    assert_eq!(solve(2023, 0, 3, ""), no_solver(2023, 0, 3));
+    assert_eq!(solve(2022, 1, 1, ""), no_solver(2022, 1, 1));
+}
+
+#[test]
+fn test_bad_input() {
+    // day 10 validates its input, day 9 still panics on it
+    assert!(matches!(
+        solve(2023, 10, 1, "not a pipe maze"),
+        Err(Error::Parse(_))
+    ));
+    assert!(matches!(
+        solve(2023, 9, 1, "1 2 x"),
+        Err(Error::Panicked(_))
+    ));
+}
+
+#[test]
+fn test_explain() {
+    let plan = "R 2 (#70c710)\nD 1 (#0dc571)\nL 2 (#5713f0)\nU 1 (#d2c081)";
+    let (answer, facts) = explain(2023, 18, 1, plan).unwrap();
+    assert_eq!(answer, Answer::Int(6));
+    assert_eq!(facts.get("boundary points"), Some("6"));
+    assert_eq!(facts.get("interior points (pick)"), Some("0"));
+    // solvers without explain support still answer, with nothing to show
+    let (answer, facts) = explain(2023, 9, 1, "0 3 6 9 12 15").unwrap();
+    assert_eq!(answer, Answer::Int(18));
+    assert!(facts.is_empty());
+}
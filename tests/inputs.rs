@@ -0,0 +1,72 @@
+//! Regression tests on our own puzzle inputs against the accepted answers in `answers.txt`.
+//! Inputs are not committed, so a day without one is skipped and CI without inputs passes.
+//! `AOC23_INPUTS` points them at another input directory.
+use std::{fs, path::Path, time::Duration};
+
+use aoc23::{
+    registry,
+    runner::{self, Outcome},
+    store,
+};
+
+/// Same as `aoc23 run`, the brute forces are slow in debug builds.
+const BUDGET: Duration = Duration::from_secs(300);
+
+/// Years with a test per day below, [`test_other_years`] covers the rest.
+const YEARS: &[u32] = &[2023];
+
+fn check_day(year: u32, day: u32) {
+    let path = registry::input_path(year, day);
+    let Ok(content) = fs::read_to_string(&path) else {
+        eprintln!("skipping {year} day {day}, no input at {}", path.display());
+        return;
+    };
+    let answers = store::load_answers(Path::new(store::ANSWERS_PATH)).unwrap();
+    for solver in registry::parts_of(year, day) {
+        let part = solver.part;
+        let Some(expected) = answers.get(&(year, day, part)) else {
+            eprintln!("skipping {year} day {day} part {part}, no accepted answer");
+            continue;
+        };
+        match runner::run_with_budget(solver, content.clone(), BUDGET) {
+            Outcome::Solved { answer, .. } => {
+                assert_eq!(&answer, expected, "{year} day {day} part {part}");
+            }
+            outcome => panic!("{year} day {day} part {part}: {outcome:?}"),
+        }
+    }
+}
+
+macro_rules! days {
+    ($year:literal: $($name:ident = $day:literal),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                super::check_day($year, $day);
+            }
+        )*
+    };
+}
+
+mod y2023 {
+    days!(2023:
+        day01 = 1, day02 = 2, day03 = 3, day04 = 4, day05 = 5,
+        day06 = 6, day07 = 7, day08 = 8, day09 = 9, day10 = 10,
+        day11 = 11, day12 = 12, day13 = 13, day14 = 14, day15 = 15,
+        day16 = 16, day17 = 17, day18 = 18, day19 = 19, day20 = 20,
+        day21 = 21, day22 = 22, day23 = 23, day24 = 24, day25 = 25,
+    );
+}
+
+/// Events added with `aoc23 new` before they get their own module here.
+#[test]
+fn test_other_years() {
+    for year in registry::years() {
+        if YEARS.contains(&year) {
+            continue;
+        }
+        for day in 1..=25 {
+            check_day(year, day);
+        }
+    }
+}
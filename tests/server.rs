@@ -0,0 +1,58 @@
+use std::{
+    io::{Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    thread,
+    time::Duration,
+};
+
+use aoc23::server;
+
+fn start() -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    thread::spawn(move || server::serve(listener, Duration::from_secs(5)));
+    return addr;
+}
+
+fn send(addr: SocketAddr, request: &str) -> String {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    stream.write_all(request.as_bytes()).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    return response;
+}
+
+fn post(addr: SocketAddr, path: &str, body: &str) -> String {
+    let request = format!(
+        "POST {path} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{body}",
+        body.len()
+    );
+    return send(addr, &request);
+}
+
+#[test]
+fn test_solve() {
+    let addr = start();
+    let hands = "32T3K 765\nT55J5 684\nKK677 28\nKTJJT 220\nQQQJA 483";
+    let response = post(addr, "/solve/2023/7/2", hands);
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{response}");
+    assert!(response.contains("Content-Type: application/json"));
+    assert!(response.contains("\r\n\r\n{\"year\":2023,\"day\":7,\"part\":2,\"answer\":5905,"));
+}
+
+#[test]
+fn test_errors() {
+    let addr = start();
+    let response = post(addr, "/solve/2023/10/1", "not a pipe maze");
+    assert!(response.starts_with("HTTP/1.1 422 "), "{response}");
+    assert!(response.contains("{\"error\":\"parse\",\"message\":"));
+    let response = post(addr, "/solve/2023/25/1", "");
+    assert!(response.starts_with("HTTP/1.1 404 "), "{response}");
+    let response = send(addr, "garbage\r\n\r\n");
+    assert!(response.starts_with("HTTP/1.1 400 "), "{response}");
+    let response = send(
+        addr,
+        "POST /solve/2023/9/1 HTTP/1.1\r\nContent-Length: 99999999\r\n\r\n",
+    );
+    assert!(response.starts_with("HTTP/1.1 413 "), "{response}");
+}